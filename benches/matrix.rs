@@ -0,0 +1,30 @@
+use cgm::{Deg, Mat4, Quat, SquareMatrix, Vec3};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+fn affine() -> Mat4<f32> {
+    Mat4::from_translation(Vec3::new(1.0, -2.0, 3.0))
+        * Mat4::from_rotation_y(Deg(37.0))
+        * Mat4::from_nonuniform_scale(2.0, 0.5, 3.0)
+}
+
+fn rigid() -> Mat4<f32> {
+    Mat4::from_scale_quaternion_translation(
+        Vec3::new(1.0, 1.0, 1.0),
+        Quat::new(0.5, 0.5, 0.5, 0.5),
+        Vec3::new(-4.0, 0.5, 2.0),
+    )
+}
+
+fn inverse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mat4_inverse");
+    let m = affine();
+    group.bench_function("general", |b| b.iter(|| black_box(m).inverse()));
+    group.bench_function("affine", |b| b.iter(|| black_box(m).inverse_affine()));
+    let r = rigid();
+    group.bench_function("rigid", |b| b.iter(|| black_box(r).inverse_rigid()));
+    group.bench_function("normal_matrix", |b| b.iter(|| black_box(m).normal_matrix()));
+    group.finish();
+}
+
+criterion_group!(benches, inverse);
+criterion_main!(benches);
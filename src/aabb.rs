@@ -0,0 +1,260 @@
+use crate::{Float, InnerSpace, Mat3, Mat4, Vec2, Vec3, Vector};
+
+macro_rules! impl_aabb {
+    ($Aabb:ident, $VecN:ident { $($field:ident),+ }) => {
+        /// Axis-aligned bounding box given by its minimum and maximum corner
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub struct $Aabb<S> {
+            pub min: $VecN<S>,
+            pub max: $VecN<S>,
+        }
+
+        impl<S: Float> $Aabb<S> {
+            /// Box with the given corners, which are not checked to be ordered
+            #[inline]
+            pub const fn new(min: $VecN<S>, max: $VecN<S>) -> $Aabb<S> {
+                $Aabb { min, max }
+            }
+
+            /// Box containing only `point`
+            #[inline]
+            pub fn from_point(point: $VecN<S>) -> $Aabb<S> {
+                $Aabb::new(point, point)
+            }
+
+            /// Box with the given center and half extents
+            #[inline]
+            pub fn from_center_extents(center: $VecN<S>, extents: $VecN<S>) -> $Aabb<S> {
+                $Aabb::new(center - extents, center + extents)
+            }
+
+            /// Smallest box containing all points, or `None` if there are no points
+            pub fn from_points<I>(points: I) -> Option<$Aabb<S>>
+            where
+                I: IntoIterator<Item = $VecN<S>>,
+            {
+                let mut points = points.into_iter();
+                let first = $Aabb::from_point(points.next()?);
+                Some(points.fold(first, |aabb, point| aabb.extend(point)))
+            }
+
+            /// Smallest box containing `self` and `point`
+            #[inline]
+            pub fn extend(&self, point: $VecN<S>) -> $Aabb<S> {
+                $Aabb::new(self.min.min_element_wise(point), self.max.max_element_wise(point))
+            }
+
+            /// Smallest box containing both boxes
+            #[inline]
+            pub fn union(&self, other: &$Aabb<S>) -> $Aabb<S> {
+                $Aabb::new(
+                    self.min.min_element_wise(other.min),
+                    self.max.max_element_wise(other.max),
+                )
+            }
+
+            /// Overlap of both boxes, or `None` if they do not overlap
+            pub fn intersection(&self, other: &$Aabb<S>) -> Option<$Aabb<S>> {
+                let min = self.min.max_element_wise(other.min);
+                let max = self.max.min_element_wise(other.max);
+                if $(min.$field <= max.$field)&&+ {
+                    Some($Aabb::new(min, max))
+                } else {
+                    None
+                }
+            }
+
+            /// Whether the point is inside the box or on its boundary
+            #[inline]
+            pub fn contains(&self, point: $VecN<S>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+
+            /// Whether the boxes overlap or touch
+            #[inline]
+            pub fn overlaps(&self, other: &$Aabb<S>) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+
+            #[inline]
+            pub fn center(&self) -> $VecN<S> {
+                (self.min + self.max) * S::HALF
+            }
+
+            /// Half of the size along each axis
+            #[inline]
+            pub fn extents(&self) -> $VecN<S> {
+                (self.max - self.min) * S::HALF
+            }
+
+            #[inline]
+            pub fn size(&self) -> $VecN<S> {
+                self.max - self.min
+            }
+
+            /// Point of the box closest to `point`, which is `point` itself if it is inside
+            #[inline]
+            pub fn closest_point(&self, point: $VecN<S>) -> $VecN<S> {
+                point.max_element_wise(self.min).min_element_wise(self.max)
+            }
+
+            /// Squared distance from the box to `point`, zero if the point is inside
+            #[inline]
+            pub fn squared_distance(&self, point: $VecN<S>) -> S {
+                (point - self.closest_point(point)).magnitude2()
+            }
+        }
+    };
+}
+
+impl_aabb!(Aabb2, Vec2 { x, y });
+impl_aabb!(Aabb3, Vec3 { x, y, z });
+
+impl<S: Float> Aabb2<S> {
+    #[inline]
+    pub fn area(&self) -> S {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    #[inline]
+    pub fn perimeter(&self) -> S {
+        let size = self.size();
+        (size.x + size.y) * (S::ONE + S::ONE)
+    }
+
+    /// Bounding box of the box transformed by an affine 2D transformation, computed with
+    /// Arvo's method
+    pub fn transform(&self, m: &Mat3<S>) -> Aabb2<S> {
+        let center = m.transform_vector2(self.center()) + m.z.truncate();
+        let e = self.extents();
+        let extents = (m.x.abs() * e.x + m.y.abs() * e.y).truncate();
+        Aabb2::from_center_extents(center, extents)
+    }
+}
+
+impl<S: Float> Aabb3<S> {
+    #[inline]
+    pub fn volume(&self) -> S {
+        self.size().product()
+    }
+
+    #[inline]
+    pub fn surface_area(&self) -> S {
+        let size = self.size();
+        (size.x * size.y + size.y * size.z + size.z * size.x) * (S::ONE + S::ONE)
+    }
+
+    /// Bounding box of the box transformed by an affine transformation, computed with Arvo's
+    /// method. Gives the same result as transforming the eight corners, at a fraction of the cost
+    pub fn transform(&self, m: &Mat4<S>) -> Aabb3<S> {
+        let center = m.transform_vector3(self.center()) + m.w.truncate();
+        let e = self.extents();
+        let extents = (m.x.abs() * e.x + m.y.abs() * e.y + m.z.abs() * e.z).truncate();
+        Aabb3::from_center_extents(center, extents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb2, Aabb3, Deg, Mat3, Mat4, Vec2, Vec3};
+
+    fn aabb() -> Aabb3<f64> {
+        Aabb3::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 2.0, 3.0))
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Vec3::new(1.0, 2.0, 2.5),
+            Vec3::new(-1.0, 0.0, 3.0),
+            Vec3::new(3.0, 1.0, 2.0),
+        ];
+        assert_eq!(Aabb3::from_points(points), Some(aabb()));
+        assert_eq!(Aabb3::<f64>::from_points([]), None);
+        let aabb = Aabb2::from_points([Vec2::new(1.0, -1.0)]).unwrap();
+        assert_eq!(aabb, Aabb2::from_point(Vec2::new(1.0, -1.0)));
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        let a = aabb();
+        let b = Aabb3::new(Vec3::new(2.0, 1.0, -4.0), Vec3::new(5.0, 4.0, 2.5));
+        let union = a.union(&b);
+        assert_eq!(
+            union,
+            Aabb3::new(Vec3::new(-1.0, 0.0, -4.0), Vec3::new(5.0, 4.0, 3.0))
+        );
+        let intersection = a.intersection(&b).unwrap();
+        assert_eq!(
+            intersection,
+            Aabb3::new(Vec3::new(2.0, 1.0, 2.0), Vec3::new(3.0, 2.0, 2.5))
+        );
+        assert!(a.overlaps(&b));
+
+        let c = Aabb3::from_point(Vec3::new(0.0, 5.0, 2.5));
+        assert_eq!(a.intersection(&c), None);
+        assert!(!a.overlaps(&c));
+        // Touching boxes overlap
+        let d = Aabb3::new(Vec3::new(3.0, 2.0, 3.0), Vec3::new(4.0, 4.0, 4.0));
+        assert!(a.overlaps(&d));
+        assert_eq!(
+            a.intersection(&d),
+            Some(Aabb3::from_point(Vec3::new(3.0, 2.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn test_measures() {
+        let a = aabb();
+        assert!(a.contains(Vec3::new(0.0, 1.0, 2.5)));
+        assert!(a.contains(Vec3::new(3.0, 2.0, 3.0)));
+        assert!(!a.contains(Vec3::new(0.0, 1.0, 3.5)));
+        assert_eq!(a.center(), Vec3::new(1.0, 1.0, 2.5));
+        assert_eq!(a.extents(), Vec3::new(2.0, 1.0, 0.5));
+        assert_eq!(a.size(), Vec3::new(4.0, 2.0, 1.0));
+        assert_eq!(a.volume(), 8.0);
+        assert_eq!(a.surface_area(), 28.0);
+        assert_eq!(Aabb3::from_center_extents(a.center(), a.extents()), a);
+
+        let r = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 2.0));
+        assert_eq!(r.area(), 8.0);
+        assert_eq!(r.perimeter(), 12.0);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let a = aabb();
+        let inside = Vec3::new(0.0, 1.0, 2.5);
+        assert_eq!(a.closest_point(inside), inside);
+        assert_eq!(a.squared_distance(inside), 0.0);
+        let outside = Vec3::new(5.0, 1.0, 0.0);
+        assert_eq!(a.closest_point(outside), Vec3::new(3.0, 1.0, 2.0));
+        assert_eq!(a.squared_distance(outside), 8.0);
+    }
+
+    #[test]
+    fn test_transform() {
+        let a = aabb();
+        let m = Mat4::from_translation(Vec3::new(1.0, -2.0, 0.5))
+            * Mat4::from_rotation(Vec3::new(1.0, 2.0, 2.0) / 3.0, Deg(40.0))
+            * Mat4::from_nonuniform_scale(2.0, 0.5, 1.5);
+        let mut corners = [Vec3::new(0.0, 0.0, 0.0); 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = if i & 1 == 0 { a.min.x } else { a.max.x };
+            let y = if i & 2 == 0 { a.min.y } else { a.max.y };
+            let z = if i & 4 == 0 { a.min.z } else { a.max.z };
+            *corner = m.transform_point3(Vec3::new(x, y, z));
+        }
+        let expected = Aabb3::from_points(corners).unwrap();
+        let transformed = a.transform(&m);
+        assert_approx_eq!(transformed.min, expected.min, 1e-12);
+        assert_approx_eq!(transformed.max, expected.max, 1e-12);
+
+        let r = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 1.0));
+        let m = Mat3::from_translation(Vec2::new(1.0, 1.0)) * Mat3::from_rotation_z(Deg(90.0));
+        let transformed = r.transform(&m);
+        assert_approx_eq!(transformed.min, Vec2::new(0.0, 1.0), 1e-12);
+        assert_approx_eq!(transformed.max, Vec2::new(1.0, 3.0), 1e-12);
+    }
+}
@@ -45,6 +45,33 @@ where
         (self + Self::HALF_TURN).normalize()
     }
 
+    /// Signed difference from `self` to `other` along the shortest path, in the range
+    /// [-half turn, half turn]
+    #[inline]
+    fn angle_difference(self, other: Self) -> Self {
+        (other - self).normalize_signed()
+    }
+
+    /// Interpolate from `self` towards `other` along the shortest path. The result is not
+    /// normalized, so that an amount of zero returns `self`
+    #[inline]
+    fn lerp_shortest(self, other: Self, amount: Self::Unitless) -> Self {
+        self + self.angle_difference(other) * amount
+    }
+
+    /// Move from `self` towards `target` along the shortest path by at most `max_delta`,
+    /// returning `target` once it is reached
+    fn move_towards_angle(self, target: Self, max_delta: Self) -> Self {
+        let difference = self.angle_difference(target);
+        if difference > max_delta {
+            self + max_delta
+        } else if difference < -max_delta {
+            self - max_delta
+        } else {
+            target
+        }
+    }
+
     fn sin(self) -> Self::Unitless;
 
     fn cos(self) -> Self::Unitless;
@@ -89,7 +116,7 @@ where
 {
     #[inline]
     fn from(rad: Rad<S>) -> Deg<S> {
-        Deg(rad.0 / S::DEG_RAD_RATIO)
+        Deg(rad.0 * S::DEG_RAD_RATIO)
     }
 }
 
@@ -100,7 +127,7 @@ where
 {
     #[inline]
     fn from(deg: Deg<S>) -> Rad<S> {
-        Rad(deg.0 * S::DEG_RAD_RATIO)
+        Rad(deg.0 / S::DEG_RAD_RATIO)
     }
 }
 
@@ -309,3 +336,468 @@ impl<S: Float> Neg for &Deg<S> {
         Deg(-self.0)
     }
 }
+
+/// Arc of angles starting at `start` and sweeping counterclockwise, i.e. in the direction of
+/// increasing angles, over `extent`. An extent of a full turn or more contains all angles
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleRange<A> {
+    pub start: A,
+    pub extent: A,
+}
+
+impl<A: Angle> AngleRange<A> {
+    pub const fn new(start: A, extent: A) -> AngleRange<A> {
+        AngleRange { start, extent }
+    }
+
+    /// Arc sweeping counterclockwise from `start` to `end`
+    pub fn from_bounds(start: A, end: A) -> AngleRange<A> {
+        AngleRange::new(start, (end - start).normalize())
+    }
+
+    /// Arc extending `half_width` to both sides of `center`, such as a field of view cone
+    pub fn centered(center: A, half_width: A) -> AngleRange<A> {
+        AngleRange::new(center - half_width, half_width + half_width)
+    }
+
+    #[inline]
+    pub fn end(&self) -> A {
+        self.start + self.extent
+    }
+
+    #[inline]
+    pub fn center(&self) -> A {
+        self.start + self.extent * A::Unitless::HALF
+    }
+
+    pub fn contains(&self, angle: A) -> bool {
+        self.extent >= A::FULL_TURN || (angle - self.start).normalize() <= self.extent
+    }
+
+    /// Clamp an angle to the arc. Angles outside of the arc are moved to the closer end,
+    /// normalized to the range of a full turn
+    pub fn clamp(&self, angle: A) -> A {
+        if self.contains(angle) {
+            return angle;
+        }
+        let to_start = self.start.angle_difference(angle);
+        let to_end = self.end().angle_difference(angle);
+        let abs = |a: A| if a < A::ZERO { -a } else { a };
+        if abs(to_start) <= abs(to_end) {
+            self.start.normalize()
+        } else {
+            self.end().normalize()
+        }
+    }
+}
+
+/// Angle in turns, where a full turn is 1
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
+pub struct Turn<S>(pub S);
+
+impl<S> Turn<S> {
+    pub const fn new(value: S) -> Self {
+        Self(value)
+    }
+}
+
+/// Angle in gradians, where a full turn is 400
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
+pub struct Grad<S>(pub S);
+
+impl<S> Grad<S> {
+    pub const fn new(value: S) -> Self {
+        Self(value)
+    }
+}
+
+macro_rules! impl_angle_unit {
+    ($Unit:ident, $full_turn:expr, $half_turn:expr) => {
+        impl<S: Float> Zero for $Unit<S> {
+            const ZERO: Self = $Unit(S::ZERO);
+        }
+
+        impl<S: Float> Angle for $Unit<S> {
+            type Unitless = S;
+
+            const FULL_TURN: $Unit<S> = $Unit($full_turn);
+            const HALF_TURN: $Unit<S> = $Unit($half_turn);
+
+            #[inline]
+            fn sin(self) -> S {
+                Rad::from(self).sin()
+            }
+
+            #[inline]
+            fn cos(self) -> S {
+                Rad::from(self).cos()
+            }
+
+            #[inline]
+            fn tan(self) -> S {
+                Rad::from(self).tan()
+            }
+
+            #[inline]
+            fn asin(ratio: S) -> Self {
+                Rad(ratio.asin()).into()
+            }
+
+            #[inline]
+            fn acos(ratio: S) -> Self {
+                Rad(ratio.acos()).into()
+            }
+
+            #[inline]
+            fn atan(ratio: S) -> Self {
+                Rad(ratio.atan()).into()
+            }
+
+            #[inline]
+            fn atan2(a: S, b: S) -> Self {
+                Rad(a.atan2(b)).into()
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (S, S) {
+                Rad::from(self).sin_cos()
+            }
+        }
+
+        impl_operator!(<S: Float> Add<$Unit<S>> for $Unit<S> {
+            fn add(lhs, rhs) -> $Unit<S> { $Unit(lhs.0 + rhs.0) }
+        });
+        impl_operator!(<S: Float> Sub<$Unit<S>> for $Unit<S> {
+            fn sub(lhs, rhs) -> $Unit<S> { $Unit(lhs.0 - rhs.0) }
+        });
+        impl_operator!(<S: Float> Mul<S> for $Unit<S> {
+            fn mul(lhs, rhs) -> $Unit<S> { $Unit(lhs.0 * rhs) }
+        });
+        impl_operator!(<S: Float> Div<$Unit<S>> for $Unit<S> {
+            fn div(lhs, rhs) -> S { lhs.0 / rhs.0 }
+        });
+        impl_operator!(<S: Float> Div<S> for $Unit<S> {
+            fn div(lhs, rhs) -> $Unit<S> { $Unit(lhs.0 / rhs) }
+        });
+        impl_operator!(<S: Float> Rem<$Unit<S>> for $Unit<S> {
+            fn rem(lhs, rhs) -> $Unit<S> { $Unit(lhs.0 % rhs.0) }
+        });
+        impl_assignment_operator!(<S: Float> AddAssign<$Unit<S> > for $Unit<S> {
+            fn add_assign(&mut self, other) { self.0 += other.0; }
+        });
+        impl_assignment_operator!(<S: Float> SubAssign<$Unit<S> > for $Unit<S> {
+            fn sub_assign(&mut self, other) { self.0 -= other.0; }
+        });
+        impl_assignment_operator!(<S: Float> RemAssign<$Unit<S> > for $Unit<S> {
+            fn rem_assign(&mut self, other) { self.0 %= other.0; }
+        });
+        impl_assignment_operator!(<S: Float> MulAssign<S> for $Unit<S> {
+            fn mul_assign(&mut self, scalar) { self.0 *= scalar; }
+        });
+        impl_assignment_operator!(<S: Float> DivAssign<S> for $Unit<S> {
+            fn div_assign(&mut self, scalar) { self.0 /= scalar; }
+        });
+
+        impl<S: Float> Neg for $Unit<S> {
+            type Output = $Unit<S>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $Unit(-self.0)
+            }
+        }
+
+        impl<S: Float> Neg for &$Unit<S> {
+            type Output = $Unit<S>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $Unit(-self.0)
+            }
+        }
+    };
+}
+
+impl_angle_unit!(Turn, S::ONE, S::HALF);
+impl_angle_unit!(Grad, S::GRAD_FULL_TURN, S::GRAD_HALF_TURN);
+
+/// Conversion in both directions between two angle units. Multiplying by the full turn of the
+/// target unit before dividing by the full turn of the source unit keeps conversions between
+/// the rational units exact
+macro_rules! impl_angle_conversion {
+    ($A:ident, $B:ident) => {
+        impl<S: Float> From<$A<S>> for $B<S> {
+            #[inline]
+            fn from(angle: $A<S>) -> $B<S> {
+                $B(angle.0 * $B::<S>::FULL_TURN.0 / $A::<S>::FULL_TURN.0)
+            }
+        }
+
+        impl<S: Float> From<$B<S>> for $A<S> {
+            #[inline]
+            fn from(angle: $B<S>) -> $A<S> {
+                $A(angle.0 * $A::<S>::FULL_TURN.0 / $B::<S>::FULL_TURN.0)
+            }
+        }
+    };
+}
+
+impl_angle_conversion!(Turn, Rad);
+impl_angle_conversion!(Turn, Deg);
+impl_angle_conversion!(Turn, Grad);
+impl_angle_conversion!(Grad, Rad);
+impl_angle_conversion!(Grad, Deg);
+
+/// Binary angle stored in 16 bits, where a full turn is 2^16. Arithmetic wraps around, so the
+/// angle is always normalized and behaves the same on all platforms
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BAngle16(pub u16);
+
+/// Binary angle stored in 32 bits, where a full turn is 2^32. Arithmetic wraps around, so the
+/// angle is always normalized and behaves the same on all platforms
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BAngle32(pub u32);
+
+macro_rules! impl_binary_angle {
+    ($BAngle:ident, $T:ty, $Wide:ty, $bits:expr) => {
+        impl $BAngle {
+            pub const ZERO: $BAngle = $BAngle(0);
+            pub const QUARTER_TURN: $BAngle = $BAngle(1 << ($bits - 2));
+            pub const HALF_TURN: $BAngle = $BAngle(1 << ($bits - 1));
+
+            pub const fn new(value: $T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl Add for $BAngle {
+            type Output = $BAngle;
+
+            #[inline]
+            fn add(self, other: $BAngle) -> $BAngle {
+                $BAngle(self.0.wrapping_add(other.0))
+            }
+        }
+
+        impl Sub for $BAngle {
+            type Output = $BAngle;
+
+            #[inline]
+            fn sub(self, other: $BAngle) -> $BAngle {
+                $BAngle(self.0.wrapping_sub(other.0))
+            }
+        }
+
+        impl Neg for $BAngle {
+            type Output = $BAngle;
+
+            #[inline]
+            fn neg(self) -> $BAngle {
+                $BAngle(self.0.wrapping_neg())
+            }
+        }
+
+        impl AddAssign for $BAngle {
+            #[inline]
+            fn add_assign(&mut self, other: $BAngle) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $BAngle {
+            #[inline]
+            fn sub_assign(&mut self, other: $BAngle) {
+                *self = *self - other;
+            }
+        }
+
+        impl_binary_angle!(@float $BAngle, $T, $Wide, $bits, f32);
+        impl_binary_angle!(@float $BAngle, $T, $Wide, $bits, f64);
+    };
+    (@float $BAngle:ident, $T:ty, $Wide:ty, $bits:expr, $S:ty) => {
+        impl From<$BAngle> for Turn<$S> {
+            #[inline]
+            fn from(angle: $BAngle) -> Turn<$S> {
+                Turn(angle.0 as $S / (1 as $Wide << $bits) as $S)
+            }
+        }
+
+        /// Rounds to the nearest binary angle, wrapping around to the range of a full turn
+        impl From<Turn<$S>> for $BAngle {
+            #[inline]
+            fn from(angle: Turn<$S>) -> $BAngle {
+                let scaled = angle.0.rem_euclid(1.0) * (1 as $Wide << $bits) as $S;
+                $BAngle(scaled.round() as $Wide as $T)
+            }
+        }
+
+        impl_binary_angle!(@unit $BAngle, $S, Rad);
+        impl_binary_angle!(@unit $BAngle, $S, Deg);
+        impl_binary_angle!(@unit $BAngle, $S, Grad);
+    };
+    (@unit $BAngle:ident, $S:ty, $Unit:ident) => {
+        impl From<$BAngle> for $Unit<$S> {
+            #[inline]
+            fn from(angle: $BAngle) -> $Unit<$S> {
+                Turn::<$S>::from(angle).into()
+            }
+        }
+
+        impl From<$Unit<$S>> for $BAngle {
+            #[inline]
+            fn from(angle: $Unit<$S>) -> $BAngle {
+                Turn::from(angle).into()
+            }
+        }
+    };
+}
+
+impl_binary_angle!(BAngle16, u16, u32, 16);
+impl_binary_angle!(BAngle32, u32, u64, 32);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, AngleRange, BAngle16, BAngle32, Deg, Grad, Rad, Turn};
+
+    #[test]
+    fn test_deg_rad() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        assert_approx_eq!(Rad::from(Deg(180.0)).0, PI, 1e-12);
+        assert_approx_eq!(Rad::from(Deg(-90.0)).0, -FRAC_PI_2, 1e-12);
+        assert_approx_eq!(Deg::from(Rad(PI)).0, 180.0, 1e-12);
+        assert_approx_eq!(Deg::from(Rad(FRAC_PI_2)).0, 90.0, 1e-12);
+        assert_approx_eq!(Rad::from(Deg::from(Rad(1.0))).0, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Deg::from(Turn(0.25)), Deg(90.0));
+        assert_eq!(Grad::from(Turn(0.25)), Grad(100.0));
+        assert_eq!(Turn::from(Deg(90.0)), Turn(0.25));
+        assert_eq!(Grad::from(Deg(90.0)), Grad(100.0));
+        assert_eq!(Deg::from(Grad(50.0)), Deg(45.0));
+        assert_eq!(Turn::from(Grad(300.0)), Turn(0.75));
+        assert_approx_eq!(Rad::from(Turn(0.5)).0, std::f64::consts::PI);
+        assert_approx_eq!(Rad::from(Grad(100.0)).0, std::f64::consts::FRAC_PI_2);
+        assert_approx_eq!(Turn::from(Rad(std::f64::consts::PI)).0, 0.5);
+        assert_approx_eq!(Grad::from(Rad(std::f64::consts::PI)).0, 200.0);
+        for deg in [0.0, 1.0, 33.0, 90.0, 123.0, 359.0] {
+            assert_approx_eq!(Deg::from(Rad::from(Deg(deg))).0, deg, 1e-12);
+            assert_eq!(Deg::from(Grad::from(Deg(deg))), Deg(deg));
+            assert_eq!(Deg::from(Turn::from(Deg(deg))), Deg(deg));
+        }
+    }
+
+    #[test]
+    fn test_turn_and_grad() {
+        assert_eq!(Turn(1.25).normalize(), Turn(0.25));
+        assert_eq!(Grad(-100.0).normalize(), Grad(300.0));
+        assert_eq!(Grad(300.0).normalize_signed(), Grad(-100.0));
+        assert_eq!(Turn(0.25).opposite(), Turn(0.75));
+        assert_approx_eq!(Turn(0.25).sin(), 1.0);
+        assert_approx_eq!(Grad(200.0).cos(), -1.0);
+        assert_approx_eq!(Turn::atan2(1.0, 0.0).0, 0.25);
+        assert_eq!(Turn(0.5) + Turn(0.25), Turn(0.75));
+        assert_eq!(Grad(50.0) * 3.0, Grad(150.0));
+    }
+
+    #[test]
+    fn test_angle_difference() {
+        assert_eq!(Deg(170.0).angle_difference(Deg(-170.0)), Deg(20.0));
+        assert_eq!(Deg(-170.0).angle_difference(Deg(170.0)), Deg(-20.0));
+        assert_eq!(Deg(10.0).angle_difference(Deg(350.0)), Deg(-20.0));
+        assert_eq!(Deg(0.0).angle_difference(Deg(720.0 + 90.0)), Deg(90.0));
+        assert_approx_eq!(
+            Rad(3.0).angle_difference(Rad(-3.0)).0,
+            std::f64::consts::TAU - 6.0,
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_lerp_shortest() {
+        assert_eq!(Deg(170.0).lerp_shortest(Deg(-170.0), 0.5), Deg(180.0));
+        assert_eq!(Deg(350.0).lerp_shortest(Deg(10.0), 0.25), Deg(355.0));
+        assert_eq!(Deg(350.0).lerp_shortest(Deg(10.0), 0.0), Deg(350.0));
+        assert_eq!(
+            Deg(350.0).lerp_shortest(Deg(10.0), 1.0).normalize(),
+            Deg(10.0)
+        );
+    }
+
+    #[test]
+    fn test_move_towards_angle() {
+        assert_eq!(
+            Deg(170.0).move_towards_angle(Deg(-170.0), Deg(5.0)),
+            Deg(175.0)
+        );
+        assert_eq!(
+            Deg(-170.0).move_towards_angle(Deg(170.0), Deg(5.0)),
+            Deg(-175.0)
+        );
+        assert_eq!(
+            Deg(10.0).move_towards_angle(Deg(20.0), Deg(15.0)),
+            Deg(20.0)
+        );
+        assert_eq!(
+            Deg(10.0).move_towards_angle(Deg(-20.0), Deg(15.0)),
+            Deg(-5.0)
+        );
+    }
+
+    #[test]
+    fn test_angle_range() {
+        // Field of view wrapping around the negative x axis
+        let fov = AngleRange::centered(Deg(180.0), Deg(30.0));
+        assert_eq!(
+            (fov.start, fov.end(), fov.center()),
+            (Deg(150.0), Deg(210.0), Deg(180.0))
+        );
+        assert!(fov.contains(Deg(-170.0)));
+        assert!(fov.contains(Deg(155.0)));
+        assert!(!fov.contains(Deg(140.0)));
+        assert!(!fov.contains(Deg(0.0)));
+        assert_eq!(fov.clamp(Deg(-175.0)), Deg(-175.0));
+        assert_eq!(fov.clamp(Deg(100.0)), Deg(150.0));
+        assert_eq!(fov.clamp(Deg(-100.0)), Deg(210.0));
+
+        let arc = AngleRange::from_bounds(Deg(-45.0), Deg(45.0));
+        assert_eq!(arc.extent, Deg(90.0));
+        assert!(arc.contains(Deg(350.0)));
+        assert!(!arc.contains(Deg(180.0)));
+        let reversed = AngleRange::from_bounds(Deg(45.0), Deg(-45.0));
+        assert_eq!(reversed.extent, Deg(270.0));
+        assert!(reversed.contains(Deg(180.0)));
+        assert!(AngleRange::new(Deg(10.0), Deg(360.0)).contains(Deg(5.0)));
+    }
+
+    #[test]
+    fn test_binary_angle() {
+        assert_eq!(BAngle16::from(Deg(90.0)), BAngle16::QUARTER_TURN);
+        assert_eq!(BAngle16::from(Turn(-0.5)), BAngle16::HALF_TURN);
+        assert_eq!(BAngle16::from(Turn(1.0)), BAngle16::ZERO);
+        assert_eq!(
+            BAngle32::from(Rad(std::f64::consts::PI)),
+            BAngle32::HALF_TURN
+        );
+        assert_eq!(Deg::<f32>::from(BAngle16(0x2000)), Deg(45.0));
+        assert_eq!(Turn::<f64>::from(BAngle32::HALF_TURN), Turn(0.5));
+
+        // Wrapping arithmetic
+        let heading = BAngle16(0xF000) + BAngle16::QUARTER_TURN;
+        assert_eq!(heading, BAngle16(0x3000));
+        assert_eq!(BAngle16::ZERO - BAngle16::QUARTER_TURN, BAngle16(0xC000));
+        assert_eq!(-BAngle32::QUARTER_TURN, BAngle32(0xC000_0000));
+
+        // Round trip is exact for every 16 bit value
+        for value in (0..=u16::MAX).step_by(257) {
+            let angle = BAngle16(value);
+            assert_eq!(BAngle16::from(Rad::<f32>::from(angle)), angle);
+            assert_eq!(BAngle16::from(Grad::<f64>::from(angle)), angle);
+        }
+    }
+}
@@ -0,0 +1,614 @@
+use crate::{Angle, Float, InnerSpace, Mat3, Mat4, Projection, Quat, Rad, Vec2, Vec3, Zero};
+
+// Cameras use a right-handed, y-up world. Zero yaw and pitch look down the negative z axis,
+// positive yaw turns left and positive pitch looks up.
+
+/// Interpolation factor moving a smoothed value towards its target over the time step `dt`.
+/// `smoothing` is the time constant of the exponential decay, zero disables smoothing
+#[inline]
+fn smoothing_factor<S: Float>(smoothing: S, dt: S) -> S {
+    if smoothing <= S::ZERO {
+        S::ONE
+    } else {
+        S::ONE - (-dt / smoothing).exp()
+    }
+}
+
+#[inline]
+fn clamp<T: PartialOrd>(value: T, (min, max): (T, T)) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Right, up and forward vectors of a camera with the given yaw and pitch
+fn orientation<S: Float>(yaw: Rad<S>, pitch: Rad<S>) -> (Vec3<S>, Vec3<S>, Vec3<S>) {
+    let (sy, cy) = yaw.sin_cos();
+    let (sp, cp) = pitch.sin_cos();
+    let right = Vec3::new(cy, S::ZERO, -sy);
+    let forward = Vec3::new(-sy * cp, sp, -cy * cp);
+    (right, right.cross(forward), forward)
+}
+
+fn view_matrix<S: Float>(eye: Vec3<S>, yaw: Rad<S>, pitch: Rad<S>) -> Mat4<S> {
+    // The camera's own up vector rather than the world's keeps the basis right when pitch
+    // limits allow looking past straight up
+    let (_, up, forward) = orientation(yaw, pitch);
+    Mat4::look_to_rh(eye, forward, up)
+}
+
+fn default_pitch_limits<S: Float>() -> (Rad<S>, Rad<S>) {
+    // Slightly less than a quarter turn to keep the view direction away from the up vector
+    let limit = Rad::HALF_TURN * S::HALF * (S::ONE - S::EPSILON.sqrt());
+    (-limit, limit)
+}
+
+/// First-person camera flying freely in the direction it looks. The camera moves towards the
+/// target state set by `rotate` and `translate`, smoothed over time by `update`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FlyCamera<S> {
+    pub position: Vec3<S>,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    pub target_position: Vec3<S>,
+    pub target_yaw: Rad<S>,
+    pub target_pitch: Rad<S>,
+    pub yaw_limits: Option<(Rad<S>, Rad<S>)>,
+    pub pitch_limits: (Rad<S>, Rad<S>),
+    /// Time constant of the smoothing in seconds, zero disables smoothing
+    pub smoothing: S,
+}
+
+impl<S: Float> FlyCamera<S> {
+    pub fn new(position: Vec3<S>, yaw: Rad<S>, pitch: Rad<S>) -> FlyCamera<S> {
+        let pitch_limits = default_pitch_limits();
+        let pitch = clamp(pitch, pitch_limits);
+        FlyCamera {
+            position,
+            yaw,
+            pitch,
+            target_position: position,
+            target_yaw: yaw,
+            target_pitch: pitch,
+            yaw_limits: None,
+            pitch_limits,
+            smoothing: S::ZERO,
+        }
+    }
+
+    #[inline]
+    pub fn forward(&self) -> Vec3<S> {
+        orientation(self.yaw, self.pitch).2
+    }
+
+    #[inline]
+    pub fn right(&self) -> Vec3<S> {
+        orientation(self.yaw, self.pitch).0
+    }
+
+    #[inline]
+    pub fn up(&self) -> Vec3<S> {
+        orientation(self.yaw, self.pitch).1
+    }
+
+    /// Turn the camera, respecting the yaw and pitch limits
+    pub fn rotate(&mut self, yaw: Rad<S>, pitch: Rad<S>) {
+        self.target_yaw += yaw;
+        if let Some(limits) = self.yaw_limits {
+            self.target_yaw = clamp(self.target_yaw, limits);
+        }
+        self.target_pitch = clamp(self.target_pitch + pitch, self.pitch_limits);
+    }
+
+    /// Move the camera relative to its target orientation
+    pub fn translate(&mut self, right: S, up: S, forward: S) {
+        let (r, u, f) = orientation(self.target_yaw, self.target_pitch);
+        self.target_position += r * right + u * up + f * forward;
+    }
+
+    /// Advance the smoothing by the time step `dt` in seconds
+    pub fn update(&mut self, dt: S) {
+        let t = smoothing_factor(self.smoothing, dt);
+        self.position += (self.target_position - self.position) * t;
+        self.yaw += (self.target_yaw - self.yaw) * t;
+        self.pitch += (self.target_pitch - self.pitch) * t;
+    }
+
+    pub fn view_matrix(&self) -> Mat4<S> {
+        view_matrix(self.position, self.yaw, self.pitch)
+    }
+
+    pub fn view_projection<P: Projection<S>>(&self, projection: &P) -> Mat4<S> {
+        projection.matrix() * self.view_matrix()
+    }
+}
+
+/// Camera orbiting around a center point at a given distance, as used by model viewers and
+/// editors. The camera moves towards the target state set by `rotate`, `zoom` and `pan`,
+/// smoothed over time by `update`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OrbitCamera<S> {
+    pub center: Vec3<S>,
+    pub distance: S,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    pub target_center: Vec3<S>,
+    pub target_distance: S,
+    pub target_yaw: Rad<S>,
+    pub target_pitch: Rad<S>,
+    pub yaw_limits: Option<(Rad<S>, Rad<S>)>,
+    pub pitch_limits: (Rad<S>, Rad<S>),
+    /// Minimum and maximum distance from the center, unlimited by default
+    pub distance_limits: (S, S),
+    /// Time constant of the smoothing in seconds, zero disables smoothing
+    pub smoothing: S,
+}
+
+impl<S: Float> OrbitCamera<S> {
+    pub fn new(center: Vec3<S>, distance: S, yaw: Rad<S>, pitch: Rad<S>) -> OrbitCamera<S> {
+        let pitch_limits = default_pitch_limits();
+        let pitch = clamp(pitch, pitch_limits);
+        OrbitCamera {
+            center,
+            distance,
+            yaw,
+            pitch,
+            target_center: center,
+            target_distance: distance,
+            target_yaw: yaw,
+            target_pitch: pitch,
+            yaw_limits: None,
+            pitch_limits,
+            distance_limits: (S::ZERO, S::INFINITY),
+            smoothing: S::ZERO,
+        }
+    }
+
+    /// Position of the camera
+    pub fn eye(&self) -> Vec3<S> {
+        self.center - orientation(self.yaw, self.pitch).2 * self.distance
+    }
+
+    /// Orbit around the center, respecting the yaw and pitch limits
+    pub fn rotate(&mut self, yaw: Rad<S>, pitch: Rad<S>) {
+        self.target_yaw += yaw;
+        if let Some(limits) = self.yaw_limits {
+            self.target_yaw = clamp(self.target_yaw, limits);
+        }
+        self.target_pitch = clamp(self.target_pitch + pitch, self.pitch_limits);
+    }
+
+    /// Multiply the distance from the center by `factor`, respecting the distance limits
+    pub fn zoom(&mut self, factor: S) {
+        self.target_distance = clamp(self.target_distance * factor, self.distance_limits);
+    }
+
+    /// Move the center in the view plane, by amounts relative to the distance from the center
+    pub fn pan(&mut self, right: S, up: S) {
+        let (r, u, _) = orientation(self.target_yaw, self.target_pitch);
+        self.target_center += (r * right + u * up) * self.target_distance;
+    }
+
+    /// Advance the smoothing by the time step `dt` in seconds
+    pub fn update(&mut self, dt: S) {
+        let t = smoothing_factor(self.smoothing, dt);
+        self.center += (self.target_center - self.center) * t;
+        self.distance += (self.target_distance - self.distance) * t;
+        self.yaw += (self.target_yaw - self.yaw) * t;
+        self.pitch += (self.target_pitch - self.pitch) * t;
+    }
+
+    pub fn view_matrix(&self) -> Mat4<S> {
+        view_matrix(self.eye(), self.yaw, self.pitch)
+    }
+
+    pub fn view_projection<P: Projection<S>>(&self, projection: &P) -> Mat4<S> {
+        projection.matrix() * self.view_matrix()
+    }
+}
+
+/// Point on the virtual trackball under a screen position, given in `[-1, 1]` with y up.
+/// Positions outside the ball map to its silhouette
+fn arcball_point<S: Float>(p: Vec2<S>) -> Vec3<S> {
+    let length2 = p.magnitude2();
+    if length2 <= S::ONE {
+        Vec3::new(p.x, p.y, (S::ONE - length2).sqrt())
+    } else {
+        (p / length2.sqrt()).extend(S::ZERO)
+    }
+}
+
+/// Camera orbiting around a center point with a free orientation, turned by dragging a virtual
+/// trackball following Shoemake's arcball. Unlike `OrbitCamera` it can roll and pass over the
+/// poles, so it has no yaw or pitch limits. The camera moves towards the target state set by
+/// `drag`, `zoom` and `pan`, smoothed over time by `update`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ArcballCamera<S> {
+    pub center: Vec3<S>,
+    pub distance: S,
+    /// Orientation turning the negative z axis into the view direction and the y axis into the
+    /// up direction of the view
+    pub rotation: Quat<S>,
+    pub target_center: Vec3<S>,
+    pub target_distance: S,
+    pub target_rotation: Quat<S>,
+    /// Minimum and maximum distance from the center, unlimited by default
+    pub distance_limits: (S, S),
+    /// Time constant of the smoothing in seconds, zero disables smoothing
+    pub smoothing: S,
+}
+
+impl<S: Float> ArcballCamera<S> {
+    /// Assumes a normalized rotation quaternion
+    pub fn new(center: Vec3<S>, distance: S, rotation: Quat<S>) -> ArcballCamera<S> {
+        ArcballCamera {
+            center,
+            distance,
+            rotation,
+            target_center: center,
+            target_distance: distance,
+            target_rotation: rotation,
+            distance_limits: (S::ZERO, S::INFINITY),
+            smoothing: S::ZERO,
+        }
+    }
+
+    /// Position of the camera
+    pub fn eye(&self) -> Vec3<S> {
+        self.center + self.rotation * Vec3::Z * self.distance
+    }
+
+    /// Turn the scene as if grabbing the trackball at the screen position `from` and dragging
+    /// it to `to`. Positions are given in `[-1, 1]` with y up, the ball fills the square
+    pub fn drag(&mut self, from: Vec2<S>, to: Vec2<S>) {
+        let arc = Quat::from_arc(arcball_point(from), arcball_point(to));
+        // The ball turns by `arc` in view space, so the camera turns the opposite way
+        self.target_rotation = (self.target_rotation * arc.conjugate()).normalize();
+    }
+
+    /// Multiply the distance from the center by `factor`, respecting the distance limits
+    pub fn zoom(&mut self, factor: S) {
+        self.target_distance = clamp(self.target_distance * factor, self.distance_limits);
+    }
+
+    /// Move the center in the view plane, by amounts relative to the distance from the center
+    pub fn pan(&mut self, right: S, up: S) {
+        let offset = Vec3::new(right, up, S::ZERO) * self.target_distance;
+        self.target_center += self.target_rotation * offset;
+    }
+
+    /// Advance the smoothing by the time step `dt` in seconds
+    pub fn update(&mut self, dt: S) {
+        let t = smoothing_factor(self.smoothing, dt);
+        self.center += (self.target_center - self.center) * t;
+        self.distance += (self.target_distance - self.distance) * t;
+        self.rotation = self.rotation.slerp(self.target_rotation, t);
+    }
+
+    pub fn view_matrix(&self) -> Mat4<S> {
+        Mat4::from(Mat3::from(self.rotation.conjugate())) * Mat4::from_translation(-self.eye())
+    }
+
+    pub fn view_projection<P: Projection<S>>(&self, projection: &P) -> Mat4<S> {
+        projection.matrix() * self.view_matrix()
+    }
+}
+
+/// Rotate a 2D vector counterclockwise by `angle`
+#[inline]
+fn rotate_vec2<S: Float>(v: Vec2<S>, angle: Rad<S>) -> Vec2<S> {
+    let (s, c) = angle.sin_cos();
+    Vec2::new(c * v.x - s * v.y, s * v.x + c * v.y)
+}
+
+/// Pan and zoom camera for 2D scenes in the xy plane, looking down the negative z axis.
+/// `zoom` is the scale from world units to view units, to be combined with an `Ortho`
+/// projection. The camera moves towards the target state set by `pan`, `zoom_at` and `rotate`,
+/// smoothed over time by `update`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Camera2d<S> {
+    pub center: Vec2<S>,
+    pub zoom: S,
+    pub rotation: Rad<S>,
+    pub target_center: Vec2<S>,
+    pub target_zoom: S,
+    pub target_rotation: Rad<S>,
+    /// Minimum and maximum zoom, by default the range in which the scale stays invertible
+    pub zoom_limits: (S, S),
+    pub rotation_limits: Option<(Rad<S>, Rad<S>)>,
+    /// World point held at a view position by `zoom_at` until the zoom settles
+    pub zoom_anchor: Option<(Vec2<S>, Vec2<S>)>,
+    /// Time constant of the smoothing in seconds, zero disables smoothing
+    pub smoothing: S,
+}
+
+impl<S: Float> Camera2d<S> {
+    pub fn new(center: Vec2<S>, zoom: S) -> Camera2d<S> {
+        Camera2d {
+            center,
+            zoom,
+            rotation: Rad::ZERO,
+            target_center: center,
+            target_zoom: zoom,
+            target_rotation: Rad::ZERO,
+            zoom_limits: (S::EPSILON, S::EPSILON.recip()),
+            rotation_limits: None,
+            zoom_anchor: None,
+            smoothing: S::ZERO,
+        }
+    }
+
+    /// Move the center by a distance given in view units. Ends the anchoring of `zoom_at`
+    pub fn pan(&mut self, delta: Vec2<S>) {
+        self.target_center += rotate_vec2(delta, self.target_rotation) / self.target_zoom;
+        self.zoom_anchor = None;
+    }
+
+    /// Multiply the zoom by `factor`, respecting the zoom limits, while keeping the world
+    /// point `anchor` where it currently is in the view, also while the zoom is smoothed
+    pub fn zoom_at(&mut self, factor: S, anchor: Vec2<S>) {
+        // The current view position, a zoom in progress has not reached its target yet
+        let view = rotate_vec2(anchor - self.center, -self.rotation) * self.zoom;
+        self.target_zoom = clamp(self.target_zoom * factor, self.zoom_limits);
+        self.target_center = anchor - rotate_vec2(view, self.target_rotation) / self.target_zoom;
+        self.zoom_anchor = Some((anchor, view));
+    }
+
+    /// Rotate the view, respecting the rotation limits. Ends the anchoring of `zoom_at`
+    pub fn rotate(&mut self, angle: Rad<S>) {
+        self.target_rotation += angle;
+        if let Some(limits) = self.rotation_limits {
+            self.target_rotation = clamp(self.target_rotation, limits);
+        }
+        self.zoom_anchor = None;
+    }
+
+    /// Advance the smoothing by the time step `dt` in seconds
+    pub fn update(&mut self, dt: S) {
+        let t = smoothing_factor(self.smoothing, dt);
+        self.zoom += (self.target_zoom - self.zoom) * t;
+        self.rotation += (self.target_rotation - self.rotation) * t;
+        match self.zoom_anchor {
+            // Linear steps of zoom and center would let the anchor drift while zooming
+            Some((anchor, view)) => {
+                self.center = anchor - rotate_vec2(view, self.rotation) / self.zoom;
+                // Smoothing stalls a few units in the last place short of the target
+                if (self.target_zoom - self.zoom).abs() <= S::EPSILON.sqrt() * self.target_zoom {
+                    self.zoom_anchor = None;
+                }
+            }
+            None => self.center += (self.target_center - self.center) * t,
+        }
+    }
+
+    pub fn view_matrix(&self) -> Mat4<S> {
+        Mat4::from_rotation_z(-self.rotation)
+            * Mat4::from_scale(self.zoom)
+            * Mat4::from_translation(-self.center.extend(S::ZERO))
+    }
+
+    pub fn view_projection<P: Projection<S>>(&self, projection: &P) -> Mat4<S> {
+        projection.matrix() * self.view_matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ArcballCamera, Camera2d, Deg, FlyCamera, Mat3, Mat4, Matrix, MetricSpace, OrbitCamera,
+        Ortho, Projection, Quat, Rad, Vec2, Vec3,
+    };
+
+    #[test]
+    fn test_fly_camera() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let camera = FlyCamera::new(eye, Deg(90.0).into(), Deg(0.0).into());
+        assert_approx_eq!(camera.forward(), Vec3::new(-1.0, 0.0, 0.0), 1e-12);
+        let expected = Mat4::look_at(eye, eye + camera.forward(), Vec3::Y);
+        assert_approx_eq!(camera.view_matrix(), expected, 1e-12);
+
+        let camera = FlyCamera::new(eye, Deg(30.0).into(), Deg(-45.0).into());
+        let expected = Mat4::look_at(eye, eye + camera.forward(), Vec3::Y);
+        assert_approx_eq!(camera.view_matrix(), expected, 1e-12);
+        assert_approx_eq!(
+            camera.view_matrix().transform_point3(eye),
+            Vec3::new(0.0, 0.0, 0.0),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_fly_camera_limits() {
+        let mut camera = FlyCamera::new(Vec3::new(0.0, 0.0, 0.0), Rad(0.0), Rad(0.0));
+        camera.yaw_limits = Some((Deg(-45.0).into(), Deg(45.0).into()));
+        camera.rotate(Deg(60.0).into(), Deg(120.0).into());
+        camera.update(0.1);
+        assert_approx_eq!(camera.yaw.0, std::f64::consts::FRAC_PI_4, 1e-12);
+        assert!(camera.pitch.0 < std::f64::consts::FRAC_PI_2);
+        assert!(camera.pitch.0 > std::f64::consts::FRAC_PI_2 - 1e-6);
+        assert!(camera.view_matrix().x.x.is_finite());
+    }
+
+    #[test]
+    fn test_fly_camera_smoothing() {
+        let mut camera = FlyCamera::new(Vec3::new(0.0, 0.0, 0.0), Rad(0.0), Rad(0.0));
+        camera.smoothing = 0.1;
+        camera.translate(0.0, 0.0, 10.0);
+        camera.update(0.1);
+        let expected = 10.0 * (1.0 - (-1.0f64).exp());
+        assert_approx_eq!(camera.position, Vec3::new(0.0, 0.0, -expected), 1e-12);
+
+        // Two half steps end up at the same place as one full step
+        let mut halves = camera;
+        halves.update(0.05);
+        halves.update(0.05);
+        camera.update(0.1);
+        assert_approx_eq!(halves.position, camera.position, 1e-12);
+
+        for _ in 0..100 {
+            camera.update(0.1);
+        }
+        assert_approx_eq!(camera.position, camera.target_position, 1e-12);
+    }
+
+    #[test]
+    fn test_orbit_camera() {
+        let center = Vec3::new(1.0, 0.0, -2.0);
+        let mut camera = OrbitCamera::new(center, 5.0, Rad(0.0), Rad(0.0));
+        assert_approx_eq!(camera.eye(), Vec3::new(1.0, 0.0, 3.0), 1e-12);
+        let expected = Mat4::look_at(camera.eye(), center, Vec3::Y);
+        assert_approx_eq!(camera.view_matrix(), expected, 1e-12);
+
+        camera.rotate(Deg(45.0).into(), Deg(30.0).into());
+        camera.update(1.0);
+        assert_approx_eq!(camera.eye().distance(center), 5.0, 1e-12);
+        let expected = Mat4::look_at(camera.eye(), center, Vec3::Y);
+        assert_approx_eq!(camera.view_matrix(), expected, 1e-12);
+
+        camera.distance_limits = (1.0, 8.0);
+        camera.zoom(2.0);
+        camera.update(1.0);
+        assert_approx_eq!(camera.distance, 8.0, 1e-12);
+
+        camera.pan(0.5, 0.0);
+        camera.update(1.0);
+        let right = camera.view_matrix().row(0).truncate();
+        assert_approx_eq!(camera.center, center + right * 4.0, 1e-12);
+    }
+
+    #[test]
+    fn test_camera_2d() {
+        let mut camera = Camera2d::new(Vec2::new(10.0, 5.0), 2.0);
+        let ortho = Ortho {
+            left: -400.0,
+            right: 400.0,
+            bottom: -300.0,
+            top: 300.0,
+            near: -1.0,
+            far: 1.0,
+        };
+        let view_projection = camera.view_projection(&ortho);
+        // The center lands in the middle of the screen and of the [0, 1] depth range
+        let p = view_projection.transform_point3(Vec3::new(10.0, 5.0, 0.0));
+        assert_approx_eq!(p, Vec3::new(0.0, 0.0, 0.5), 1e-12);
+        let p = view_projection.transform_point3(Vec3::new(210.0, 5.0, 0.0));
+        assert_approx_eq!(p.x, 1.0, 1e-12);
+
+        let anchor = Vec2::new(30.0, -20.0);
+        let before = camera.view_matrix().transform_point3(anchor.extend(0.0));
+        camera.zoom_at(3.0, anchor);
+        camera.update(1.0);
+        let after = camera.view_matrix().transform_point3(anchor.extend(0.0));
+        assert_approx_eq!(before, after, 1e-9);
+        assert_approx_eq!(camera.zoom, 6.0, 1e-12);
+
+        camera.rotate(Deg(90.0).into());
+        camera.update(1.0);
+        let center = camera.center;
+        camera.pan(Vec2::new(6.0, 0.0));
+        camera.update(1.0);
+        assert_approx_eq!(camera.center, center + Vec2::new(0.0, 1.0), 1e-12);
+        let projection = ortho.matrix();
+        assert_approx_eq!(
+            camera.view_projection(&ortho),
+            projection * camera.view_matrix(),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_orbit_camera_default_limits() {
+        let mut camera = OrbitCamera::new(Vec3::new(0.0, 0.0, 0.0), 5.0, Rad(0.0), Rad(0.0));
+        camera.zoom(1e30);
+        camera.update(1.0);
+        assert_eq!(camera.distance, 5e30);
+    }
+
+    #[test]
+    fn test_arcball_camera() {
+        let center = Vec3::new(1.0, 0.0, -2.0);
+        let mut camera = ArcballCamera::new(center, 5.0, Quat::new(1.0, 0.0, 0.0, 0.0));
+        assert_approx_eq!(camera.eye(), Vec3::new(1.0, 0.0, 3.0), 1e-12);
+        let expected = Mat4::look_at(camera.eye(), center, Vec3::Y);
+        assert_approx_eq!(camera.view_matrix(), expected, 1e-12);
+
+        // The point of the ball under the cursor follows it
+        let (from, to) = (Vec2::new(0.0, 0.0), Vec2::new(0.5, 0.0));
+        let grabbed = center + Vec3::Z;
+        camera.drag(from, to);
+        camera.update(1.0);
+        let view = camera.view_matrix();
+        let offset = view.transform_point3(grabbed) - view.transform_point3(center);
+        assert_approx_eq!(offset, Vec3::new(0.5, 0.0, 0.75f64.sqrt()), 1e-12);
+        assert_approx_eq!(camera.eye().distance(center), 5.0, 1e-12);
+
+        // Dragging over the top passes the pole and rolls freely, unlike `OrbitCamera`
+        for _ in 0..3 {
+            camera.drag(Vec2::new(0.0, -0.9), Vec2::new(0.0, 0.9));
+        }
+        camera.drag(Vec2::new(0.9, 0.0), Vec2::new(0.0, 0.9));
+        camera.update(1.0);
+        assert!(Mat3::from(camera.view_matrix()).is_orthonormal(1e-12));
+        assert_approx_eq!(
+            camera.view_matrix().transform_point3(center),
+            Vec3::new(0.0, 0.0, -5.0),
+            1e-12
+        );
+
+        camera.pan(0.0, 0.2);
+        camera.update(1.0);
+        let up = camera.view_matrix().row(1).truncate();
+        assert_approx_eq!(camera.center, center + up, 1e-12);
+    }
+
+    #[test]
+    fn test_arcball_camera_smoothing() {
+        let center = Vec3::new(0.0, 0.0, 0.0);
+        let mut camera = ArcballCamera::new(center, 2.0, Quat::new(1.0, 0.0, 0.0, 0.0));
+        camera.smoothing = 0.2;
+        camera.drag(Vec2::new(-0.5, 0.0), Vec2::new(0.5, 0.5));
+        camera.zoom(3.0);
+        camera.update(0.1);
+        assert!(camera.distance > 2.0 && camera.distance < 6.0);
+        assert!(camera.rotation.angle_between(camera.target_rotation).0 > 0.0);
+        for _ in 0..200 {
+            camera.update(0.1);
+        }
+        assert_approx_eq!(camera.distance, 6.0, 1e-9);
+        assert_approx_eq!(camera.rotation, camera.target_rotation, 1e-9);
+    }
+
+    #[test]
+    fn test_camera_2d_smoothed_zoom() {
+        let mut camera = Camera2d::new(Vec2::new(10.0, 5.0), 2.0);
+        camera.smoothing = 0.2;
+        camera.rotate(Deg(30.0).into());
+        camera.update(10.0);
+        let anchor = Vec2::new(30.0, -20.0);
+        let screen = |camera: &Camera2d<f64>| {
+            camera
+                .view_matrix()
+                .transform_point3(anchor.extend(0.0))
+                .truncate()
+        };
+        let before = screen(&camera);
+        camera.zoom_at(3.0, anchor);
+        for _ in 0..5 {
+            camera.update(0.05);
+            assert_approx_eq!(screen(&camera), before, 1e-9);
+        }
+        // Zooming again before the first zoom settles keeps the anchor in place too
+        camera.zoom_at(0.5, anchor);
+        for _ in 0..200 {
+            camera.update(0.05);
+            assert_approx_eq!(screen(&camera), before, 1e-9);
+        }
+        assert_approx_eq!(camera.zoom, 3.0, 1e-9);
+        assert_approx_eq!(camera.center, camera.target_center, 1e-9);
+        assert_eq!(camera.zoom_anchor, None);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Aabb3, Float, Segment3, Vec3};
+
+/// Sphere swept along a segment
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule<S> {
+    pub segment: Segment3<S>,
+    pub radius: S,
+}
+
+impl<S: Float> Capsule<S> {
+    #[inline]
+    pub const fn new(start: Vec3<S>, end: Vec3<S>, radius: S) -> Capsule<S> {
+        Capsule {
+            segment: Segment3::new(start, end),
+            radius,
+        }
+    }
+
+    /// Whether the point is inside the capsule or on its boundary
+    #[inline]
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        self.segment.squared_distance(point) <= self.radius * self.radius
+    }
+
+    /// Smallest axis-aligned box containing the capsule
+    pub fn aabb(&self) -> Aabb3<S> {
+        let r = Vec3::new(self.radius, self.radius, self.radius);
+        let (start, end) = (self.segment.start, self.segment.end);
+        Aabb3::new(
+            start.min_element_wise(end) - r,
+            start.max_element_wise(end) + r,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb3, Capsule, Vec3};
+
+    #[test]
+    fn test_capsule() {
+        let capsule = Capsule::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0), 1.0);
+        assert!(capsule.contains(Vec3::new(1.0, 2.0, 0.0)));
+        assert!(capsule.contains(Vec3::new(0.0, 5.0, 0.0)));
+        assert!(!capsule.contains(Vec3::new(0.8, 4.8, 0.0)));
+        assert_eq!(
+            capsule.aabb(),
+            Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 5.0, 1.0))
+        );
+    }
+}
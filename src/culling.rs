@@ -0,0 +1,246 @@
+use crate::{
+    Aabb3, DepthRange, Float, InnerSpace, Mat4, Matrix, Sphere, SquareMatrix, Vec3, Vec4, Zero,
+};
+
+/// Result of testing a bounding volume against a view volume
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Intersection {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+/// Planes bounding the view volume of a view-projection matrix, extracted using the
+/// Gribb-Hartmann method. Each plane is stored as a `Vec4` with a unit normal in `xyz` pointing
+/// into the view volume and the distance in `w`, so that `normal.dot(p) + w` is the signed
+/// distance of the point `p` from the plane, positive on the inner side.
+/// A plane at infinity, such as the far plane of an infinite projection, never culls anything
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FrustumPlanes<S> {
+    /// Left, right, bottom, top, near and far plane
+    pub planes: [Vec4<S>; 6],
+}
+
+impl<S: Float> FrustumPlanes<S> {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    /// Extract the planes of a view-projection matrix built for the given depth range. With
+    /// reversed depth or a flipped y axis the near and far, or bottom and top planes swap places
+    pub fn from_matrix(m: &Mat4<S>, depth: DepthRange) -> FrustumPlanes<S> {
+        let (x, y, z, w) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        let near = match depth {
+            DepthRange::NegativeOneToOne => w + z,
+            DepthRange::ZeroToOne => z,
+        };
+        FrustumPlanes {
+            planes: [w + x, w - x, w + y, w - y, near, w - z].map(Self::normalize_plane),
+        }
+    }
+
+    fn normalize_plane(plane: Vec4<S>) -> Vec4<S> {
+        let length = plane.truncate().magnitude();
+        if length == S::ZERO {
+            Vec4::W
+        } else {
+            plane / length
+        }
+    }
+
+    #[inline]
+    fn distance(plane: Vec4<S>, point: Vec3<S>) -> S {
+        plane.truncate().dot(point) + plane.w
+    }
+
+    /// Corners of the view volume of a view-projection matrix built for the given depth range,
+    /// in the order near bottom-left, bottom-right, top-left, top-right, followed by the far
+    /// corners in the same order. Returns `None` if the matrix is not invertible. The far
+    /// corners of an infinite projection are not finite
+    pub fn corners(m: &Mat4<S>, depth: DepthRange) -> Option<[Vec3<S>; 8]> {
+        let inv = m.inverse()?;
+        let near = match depth {
+            DepthRange::NegativeOneToOne => -S::ONE,
+            DepthRange::ZeroToOne => S::ZERO,
+        };
+        let mut corners = [Vec3::ZERO; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = if i & 1 == 0 { -S::ONE } else { S::ONE };
+            let y = if i & 2 == 0 { -S::ONE } else { S::ONE };
+            let z = if i & 4 == 0 { near } else { S::ONE };
+            *corner = inv.transform_point3(Vec3::new(x, y, z));
+        }
+        Some(corners)
+    }
+
+    pub fn contains_point(&self, point: Vec3<S>) -> bool {
+        self.planes
+            .iter()
+            .all(|&plane| Self::distance(plane, point) >= S::ZERO)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<S>) -> Intersection {
+        let radius = sphere.radius;
+        let mut result = Intersection::Inside;
+        for &plane in &self.planes {
+            let distance = Self::distance(plane, sphere.center);
+            if distance < -radius {
+                return Intersection::Outside;
+            }
+            if distance < radius {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Test an axis-aligned box. Boxes close to the edges of the view volume may be reported
+    /// as intersecting even when they are outside
+    pub fn intersects_aabb(&self, aabb: &Aabb3<S>) -> Intersection {
+        let (min, max) = (aabb.min, aabb.max);
+        let mut result = Intersection::Inside;
+        for &plane in &self.planes {
+            let select = |positive: bool, normal: S, lo: S, hi: S| {
+                if (normal >= S::ZERO) == positive {
+                    hi
+                } else {
+                    lo
+                }
+            };
+            // Corners farthest along and against the plane normal
+            let farthest = Vec3::new(
+                select(true, plane.x, min.x, max.x),
+                select(true, plane.y, min.y, max.y),
+                select(true, plane.z, min.z, max.z),
+            );
+            if Self::distance(plane, farthest) < S::ZERO {
+                return Intersection::Outside;
+            }
+            let nearest = Vec3::new(
+                select(false, plane.x, min.x, max.x),
+                select(false, plane.y, min.y, max.y),
+                select(false, plane.z, min.z, max.z),
+            );
+            if Self::distance(plane, nearest) < S::ZERO {
+                result = Intersection::Intersecting;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Aabb3, ClipSpace, Deg, DepthRange, FrustumPlanes, Intersection, Mat4, Perspective,
+        Projection, Sphere, Vec3,
+    };
+
+    const PERSP: Perspective<f64> = Perspective {
+        fovy: crate::Rad(std::f64::consts::FRAC_PI_2),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+    };
+    const PERSP_DEPTH: DepthRange = ClipSpace::VULKAN.depth;
+
+    fn view() -> Mat4<f64> {
+        Mat4::look_at(Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, 0.0), Vec3::Y)
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let conventions = [ClipSpace::OPENGL, ClipSpace::VULKAN, ClipSpace::WEBGPU];
+        for clip in conventions {
+            let m = PERSP.to_mat4(clip) * view();
+            let planes = FrustumPlanes::from_matrix(&m, clip.depth);
+            assert!(planes.contains_point(Vec3::new(0.0, 0.0, 0.0)));
+            assert!(planes.contains_point(Vec3::new(0.0, 0.0, -4.9)));
+            assert!(!planes.contains_point(Vec3::new(0.0, 0.0, -5.1)));
+            assert!(!planes.contains_point(Vec3::new(0.0, 0.0, 4.5)));
+            assert!(planes.contains_point(Vec3::new(2.9, 0.0, 2.0)));
+            assert!(!planes.contains_point(Vec3::new(3.1, 0.0, 2.0)));
+            assert!(!planes.contains_point(Vec3::new(0.0, -3.1, 2.0)));
+        }
+    }
+
+    #[test]
+    fn test_infinite_and_reverse_z() {
+        for m in [
+            PERSP.infinite().to_mat4(ClipSpace::VULKAN),
+            PERSP.infinite().reverse_z().to_mat4(ClipSpace::VULKAN),
+            PERSP.reverse_z().to_mat4(ClipSpace::WEBGPU),
+        ] {
+            let planes = FrustumPlanes::from_matrix(&(m * view()), ClipSpace::VULKAN.depth);
+            assert!(planes.contains_point(Vec3::new(0.0, 0.0, -4.9)));
+            assert!(!planes.contains_point(Vec3::new(0.0, 0.0, 4.5)));
+        }
+        let m = PERSP.infinite().matrix() * view();
+        let planes = FrustumPlanes::from_matrix(&m, ClipSpace::VULKAN.depth);
+        assert!(planes.contains_point(Vec3::new(0.0, 0.0, -1e9)));
+    }
+
+    #[test]
+    fn test_intersects_sphere() {
+        let planes = FrustumPlanes::from_matrix(&(PERSP.matrix() * view()), PERSP_DEPTH);
+        let sphere = |x, y, z, r| planes.intersects_sphere(&Sphere::new(Vec3::new(x, y, z), r));
+        assert_eq!(sphere(0.0, 0.0, 0.0, 1.0), Intersection::Inside);
+        assert_eq!(sphere(0.0, 0.0, -5.0, 1.0), Intersection::Intersecting);
+        assert_eq!(sphere(0.0, 0.0, -7.0, 1.0), Intersection::Outside);
+        assert_eq!(sphere(10.0, 0.0, 0.0, 1.0), Intersection::Outside);
+        assert_eq!(sphere(5.0, 0.0, 0.0, 1.0), Intersection::Intersecting);
+    }
+
+    #[test]
+    fn test_intersects_aabb() {
+        let planes = FrustumPlanes::from_matrix(&(PERSP.matrix() * view()), PERSP_DEPTH);
+        let aabb = |min: (f64, f64, f64), max: (f64, f64, f64)| {
+            planes.intersects_aabb(&Aabb3::new(Vec3::from(min), Vec3::from(max)))
+        };
+        assert_eq!(
+            aabb((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0)),
+            Intersection::Inside
+        );
+        assert_eq!(
+            aabb((-1.0, -1.0, -6.0), (1.0, 1.0, -4.0)),
+            Intersection::Intersecting
+        );
+        assert_eq!(
+            aabb((-1.0, -1.0, 3.0), (1.0, 1.0, 8.0)),
+            Intersection::Intersecting
+        );
+        assert_eq!(
+            aabb((6.0, -1.0, -1.0), (8.0, 1.0, 1.0)),
+            Intersection::Outside
+        );
+        assert_eq!(
+            aabb((-1.0, -1.0, -9.0), (1.0, 1.0, -6.0)),
+            Intersection::Outside
+        );
+    }
+
+    #[test]
+    fn test_corners() {
+        let persp = Perspective {
+            fovy: Deg(90.0).into(),
+            ..PERSP
+        };
+        let corners = FrustumPlanes::corners(&(persp.matrix() * view()), PERSP_DEPTH).unwrap();
+        // The Vulkan convention flips y, so the bottom NDC corners are at the top
+        assert_approx_eq!(corners[0], Vec3::new(-1.0, 1.0, 4.0), 1e-12);
+        assert_approx_eq!(corners[3], Vec3::new(1.0, -1.0, 4.0), 1e-12);
+        assert_approx_eq!(corners[4], Vec3::new(-10.0, 10.0, -5.0), 1e-12);
+        assert_approx_eq!(corners[7], Vec3::new(10.0, -10.0, -5.0), 1e-12);
+
+        let planes = FrustumPlanes::from_matrix(&(persp.matrix() * view()), PERSP_DEPTH);
+        for corner in corners {
+            // Corners lie on the boundary of the view volume
+            let (center, epsilon) = (Vec3::new(0.0, 0.0, -0.5), 1e-9);
+            assert!(planes.contains_point(corner + (center - corner) * epsilon));
+            assert!(!planes.contains_point(corner - (center - corner) * epsilon));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Aabb3, Float, InnerSpace, Segment3, Vec3};
+
+/// Solid cylinder around a segment, with flat caps at both ends
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylinder<S> {
+    pub segment: Segment3<S>,
+    pub radius: S,
+}
+
+impl<S: Float> Cylinder<S> {
+    #[inline]
+    pub const fn new(start: Vec3<S>, end: Vec3<S>, radius: S) -> Cylinder<S> {
+        Cylinder {
+            segment: Segment3::new(start, end),
+            radius,
+        }
+    }
+
+    /// Whether the point is inside the cylinder or on its boundary
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        let axis = self.segment.end - self.segment.start;
+        let axis2 = axis.magnitude2();
+        let p = point - self.segment.start;
+        let y = p.dot(axis);
+        if y < S::ZERO || y > axis2 {
+            return false;
+        }
+        (p - axis * (y / axis2)).magnitude2() <= self.radius * self.radius
+    }
+
+    /// Smallest axis-aligned box containing the cylinder
+    pub fn aabb(&self) -> Aabb3<S> {
+        let (start, end) = (self.segment.start, self.segment.end);
+        let axis = (end - start).normalize();
+        // Extent of the cap discs along each coordinate axis
+        let disc = |a: S| (S::ONE - a * a).max(S::ZERO).sqrt() * self.radius;
+        let r = Vec3::new(disc(axis.x), disc(axis.y), disc(axis.z));
+        Aabb3::new(
+            start.min_element_wise(end) - r,
+            start.max_element_wise(end) + r,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb3, Cylinder, Vec3};
+
+    #[test]
+    fn test_cylinder() {
+        let cylinder = Cylinder::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0), 1.0);
+        assert!(cylinder.contains(Vec3::new(1.0, 2.0, 0.0)));
+        assert!(cylinder.contains(Vec3::new(0.6, 4.0, 0.6)));
+        assert!(!cylinder.contains(Vec3::new(0.0, 4.1, 0.0)));
+        assert!(!cylinder.contains(Vec3::new(0.8, 2.0, 0.8)));
+        assert_eq!(
+            cylinder.aabb(),
+            Aabb3::new(Vec3::new(-1.0, 0.0, -1.0), Vec3::new(1.0, 4.0, 1.0))
+        );
+        let tilted = Cylinder::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(3.0, 4.0, 0.0), 1.0);
+        let aabb = tilted.aabb();
+        assert_approx_eq!(aabb.min, Vec3::new(-0.8, -0.6, -1.0), 1e-12);
+        assert_approx_eq!(aabb.max, Vec3::new(3.8, 4.6, 1.0), 1e-12);
+    }
+}
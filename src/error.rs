@@ -0,0 +1,44 @@
+use crate::ProjectionError;
+
+/// Error returned by the checked conversions and decompositions of the crate
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The matrix is not a rotation, i.e. its columns are not orthogonal unit vectors or it
+    /// contains a reflection
+    NotOrthonormal,
+    /// The matrix is not invertible, or a scale factor is zero
+    Singular,
+    /// An input contains infinite or NaN elements
+    NonFinite,
+    /// The last row of the matrix is not `[0, 0, 0, 1]`
+    NotAffine,
+    /// Projection parameters could not be recovered from a matrix
+    Projection(ProjectionError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotOrthonormal => f.write_str("matrix is not orthonormal"),
+            Error::Singular => f.write_str("matrix is singular"),
+            Error::NonFinite => f.write_str("input is not finite"),
+            Error::NotAffine => f.write_str("matrix is not an affine transformation"),
+            Error::Projection(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Projection(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProjectionError> for Error {
+    fn from(err: ProjectionError) -> Error {
+        Error::Projection(err)
+    }
+}
@@ -1,10 +1,13 @@
-use crate::{Angle, Float, Mat3, Mat4, One, Quat, Rad, Zero};
+use crate::{Angle, Float, Mat3, Mat4, Matrix, One, Quat, Rad, Vec3, Zero};
 
+/// Euler angles in the intrinsic `XYZ` order: a rotation about the x axis, followed by a
+/// rotation about the rotated y axis and then about the twice rotated z axis. See `EulerOrder`
+/// for other conventions
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Euler<A> {
-    pub x: A, // Pitch
-    pub y: A, // Yaw
-    pub z: A, // Roll
+    pub x: A,
+    pub y: A,
+    pub z: A,
 }
 
 impl<A> Euler<A> {
@@ -88,3 +91,296 @@ impl<S: Float> From<Quat<S>> for Euler<Rad<S>> {
         }
     }
 }
+
+impl<S: Float> From<Mat3<S>> for Euler<Rad<S>> {
+    fn from(src: Mat3<S>) -> Euler<Rad<S>> {
+        let (x, y, z) = src.to_euler(EulerOrder::Intrinsic(EulerAxes::XYZ));
+        Euler { x, y, z }
+    }
+}
+
+impl<S: Float> From<Mat4<S>> for Euler<Rad<S>> {
+    fn from(src: Mat4<S>) -> Euler<Rad<S>> {
+        Euler::from(Mat3::from(src))
+    }
+}
+
+/// Sequence of the three rotation axes of Euler angles. The Tait-Bryan sequences use three
+/// different axes, the proper Euler sequences rotate about the first axis again at the end
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EulerAxes {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// Indices of the first, second and third axis, and whether the sequence is a proper Euler
+    /// sequence, in which case the third index is the axis not in the sequence
+    fn indices(self) -> (usize, usize, usize, bool) {
+        match self {
+            EulerAxes::XYZ => (0, 1, 2, false),
+            EulerAxes::XZY => (0, 2, 1, false),
+            EulerAxes::YXZ => (1, 0, 2, false),
+            EulerAxes::YZX => (1, 2, 0, false),
+            EulerAxes::ZXY => (2, 0, 1, false),
+            EulerAxes::ZYX => (2, 1, 0, false),
+            EulerAxes::XYX => (0, 1, 2, true),
+            EulerAxes::XZX => (0, 2, 1, true),
+            EulerAxes::YXY => (1, 0, 2, true),
+            EulerAxes::YZY => (1, 2, 0, true),
+            EulerAxes::ZXZ => (2, 0, 1, true),
+            EulerAxes::ZYZ => (2, 1, 0, true),
+        }
+    }
+
+    fn reverse(self) -> EulerAxes {
+        match self {
+            EulerAxes::XYZ => EulerAxes::ZYX,
+            EulerAxes::XZY => EulerAxes::YZX,
+            EulerAxes::YXZ => EulerAxes::ZXY,
+            EulerAxes::YZX => EulerAxes::XZY,
+            EulerAxes::ZXY => EulerAxes::YXZ,
+            EulerAxes::ZYX => EulerAxes::XYZ,
+            proper => proper,
+        }
+    }
+}
+
+/// Convention of Euler angles. Intrinsic rotations are about the axes of the rotating frame,
+/// so `Intrinsic(XYZ)` builds the rotation `Rx * Ry * Rz`. Extrinsic rotations are about the
+/// fixed axes of the parent frame, so `Extrinsic(XYZ)` builds `Rz * Ry * Rx`. The angles are
+/// always given in the order of the axis sequence
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EulerOrder {
+    Intrinsic(EulerAxes),
+    Extrinsic(EulerAxes),
+}
+
+impl EulerOrder {
+    /// Intrinsic axis sequence of the same rotation, and whether the angles are reversed
+    fn to_intrinsic(self) -> (EulerAxes, bool) {
+        match self {
+            EulerOrder::Intrinsic(axes) => (axes, false),
+            EulerOrder::Extrinsic(axes) => (axes.reverse(), true),
+        }
+    }
+}
+
+fn axis<S: Float>(index: usize) -> Vec3<S> {
+    match index {
+        0 => Vec3::X,
+        1 => Vec3::Y,
+        _ => Vec3::Z,
+    }
+}
+
+fn component<S: Float>(v: Vec3<S>, index: usize) -> S {
+    match index {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+impl<S: Float> Mat3<S> {
+    /// Rotation matrix from Euler angles `a`, `b` and `c` about the axes of `order`
+    pub fn from_euler<A: Into<Rad<S>>>(order: EulerOrder, a: A, b: A, c: A) -> Mat3<S> {
+        let (axes, reversed) = order.to_intrinsic();
+        let (a, b, c) = if reversed { (c, b, a) } else { (a, b, c) };
+        let (i, j, k, proper) = axes.indices();
+        let k = if proper { i } else { k };
+        Mat3::from_rotation(axis(i), a)
+            * Mat3::from_rotation(axis(j), b)
+            * Mat3::from_rotation(axis(k), c)
+    }
+
+    /// Euler angles of a rotation matrix in the given order. The first and third angles are in
+    /// the range [-π, π]. The second angle is in [-π/2, π/2] for Tait-Bryan sequences and in
+    /// [0, π] for proper Euler sequences.
+    ///
+    /// At gimbal lock the first and third rotation axes coincide and only the sum or difference
+    /// of their angles is defined. The angle of the last intrinsic rotation, which is the first
+    /// extrinsic rotation, is then set to zero
+    pub fn to_euler(&self, order: EulerOrder) -> (Rad<S>, Rad<S>, Rad<S>) {
+        let (axes, reversed) = order.to_intrinsic();
+        let (i, j, k, proper) = axes.indices();
+        let m = |row: usize, col: usize| component(self.column(col), row);
+        // Parity of the permutation (i, j, k)
+        let sign = if j == (i + 1) % 3 { S::ONE } else { -S::ONE };
+        let four = S::ONE + S::ONE + S::ONE + S::ONE;
+        let lock_threshold = S::EPSILON * four * four;
+
+        let (a, b, c) = if proper {
+            let sin_b = (m(i, j) * m(i, j) + m(i, k) * m(i, k)).sqrt();
+            let b = Rad::atan2(sin_b, m(i, i));
+            if sin_b > lock_threshold {
+                let a = Rad::atan2(m(j, i), -sign * m(k, i));
+                let c = Rad::atan2(m(i, j), sign * m(i, k));
+                (a, b, c)
+            } else {
+                (Rad::atan2(sign * m(k, j), m(j, j)), b, Rad::ZERO)
+            }
+        } else {
+            let cos_b = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+            let b = Rad::atan2(sign * m(i, k), cos_b);
+            if cos_b > lock_threshold {
+                let a = Rad::atan2(-sign * m(j, k), m(k, k));
+                let c = Rad::atan2(-sign * m(i, j), m(i, i));
+                (a, b, c)
+            } else {
+                (Rad::atan2(sign * m(k, j), m(j, j)), b, Rad::ZERO)
+            }
+        };
+
+        if reversed { (c, b, a) } else { (a, b, c) }
+    }
+}
+
+impl<S: Float> Mat4<S> {
+    /// Rotation matrix from Euler angles, see `Mat3::from_euler`
+    #[inline]
+    pub fn from_euler<A: Into<Rad<S>>>(order: EulerOrder, a: A, b: A, c: A) -> Mat4<S> {
+        Mat4::from(Mat3::from_euler(order, a, b, c))
+    }
+
+    /// Euler angles of the rotation part of the matrix, see `Mat3::to_euler`
+    #[inline]
+    pub fn to_euler(&self, order: EulerOrder) -> (Rad<S>, Rad<S>, Rad<S>) {
+        Mat3::from(*self).to_euler(order)
+    }
+}
+
+impl<S: Float> Quat<S> {
+    /// Rotation from Euler angles, see `Mat3::from_euler`
+    pub fn from_euler<A: Into<Rad<S>>>(order: EulerOrder, a: A, b: A, c: A) -> Quat<S> {
+        let (axes, reversed) = order.to_intrinsic();
+        let (a, b, c) = if reversed { (c, b, a) } else { (a, b, c) };
+        let (i, j, k, proper) = axes.indices();
+        let k = if proper { i } else { k };
+        Quat::from_axis_angle(axis(i), a)
+            * Quat::from_axis_angle(axis(j), b)
+            * Quat::from_axis_angle(axis(k), c)
+    }
+
+    /// Euler angles of a unit quaternion, see `Mat3::to_euler`
+    #[inline]
+    pub fn to_euler(self, order: EulerOrder) -> (Rad<S>, Rad<S>, Rad<S>) {
+        Mat3::from(self).to_euler(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Deg, Euler, EulerAxes, EulerOrder, Mat3, Mat4, Quat, Rad};
+
+    const AXES: [EulerAxes; 12] = [
+        EulerAxes::XYZ,
+        EulerAxes::XZY,
+        EulerAxes::YXZ,
+        EulerAxes::YZX,
+        EulerAxes::ZXY,
+        EulerAxes::ZYX,
+        EulerAxes::XYX,
+        EulerAxes::XZX,
+        EulerAxes::YXY,
+        EulerAxes::YZY,
+        EulerAxes::ZXZ,
+        EulerAxes::ZYZ,
+    ];
+
+    fn orders() -> impl Iterator<Item = (EulerOrder, bool)> {
+        AXES.into_iter().flat_map(|axes| {
+            let proper = AXES[6..].contains(&axes);
+            [
+                (EulerOrder::Intrinsic(axes), proper),
+                (EulerOrder::Extrinsic(axes), proper),
+            ]
+        })
+    }
+
+    fn degrees(angles: (Rad<f64>, Rad<f64>, Rad<f64>)) -> (f64, f64, f64) {
+        (
+            Deg::from(angles.0).0,
+            Deg::from(angles.1).0,
+            Deg::from(angles.2).0,
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (order, proper) in orders() {
+            for (a, b, c) in [
+                (30.0, 20.0, -60.0),
+                (-150.0, 75.0, 100.0),
+                (10.0, -40.0, 170.0),
+            ] {
+                // Keep the second angle in the range returned for the sequence
+                let b = if proper { b + 90.0 } else { b };
+                let q = Quat::from_euler(order, Deg(a), Deg(b), Deg(c));
+                let m = Mat3::from_euler(order, Deg(a), Deg(b), Deg(c));
+                assert_approx_eq!(Mat3::from(q), m, 1e-12);
+
+                let (ra, rb, rc) = degrees(m.to_euler(order));
+                assert_approx_eq!(ra, a, 1e-9);
+                assert_approx_eq!(rb, b, 1e-9);
+                assert_approx_eq!(rc, c, 1e-9);
+                let (qa, qb, qc) = degrees(q.to_euler(order));
+                assert_approx_eq!(Mat3::from_euler(order, Deg(qa), Deg(qb), Deg(qc)), m, 1e-12);
+                let m4 = Mat4::from_euler(order, Deg(a), Deg(b), Deg(c));
+                assert_approx_eq!(m4, Mat4::from(m), 1e-12);
+                assert_approx_eq!(degrees(m4.to_euler(order)).0, a, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conventions() {
+        let (a, b, c) = (Deg(30.0), Deg(-20.0), Deg(45.0));
+        let rx = Mat3::from_rotation_x(a);
+        let ry = Mat3::from_rotation_y(b);
+        let rz = Mat3::from_rotation_z(c);
+        let intrinsic = Mat3::from_euler(EulerOrder::Intrinsic(EulerAxes::XYZ), a, b, c);
+        let extrinsic = Mat3::from_euler(EulerOrder::Extrinsic(EulerAxes::XYZ), a, b, c);
+        assert_approx_eq!(intrinsic, rx * ry * rz, 1e-12);
+        assert_approx_eq!(extrinsic, rz * ry * rx, 1e-12);
+        assert_approx_eq!(intrinsic, Mat3::from(Euler::new(a, b, c)), 1e-12);
+        assert_approx_eq!(
+            Mat3::from_euler(EulerOrder::Intrinsic(EulerAxes::ZYX), c, b, a),
+            extrinsic,
+            1e-12
+        );
+
+        let euler = Euler::from(intrinsic);
+        assert_approx_eq!(Deg::from(euler.x).0, 30.0, 1e-9);
+        assert_approx_eq!(Deg::from(euler.y).0, -20.0, 1e-9);
+        assert_approx_eq!(Deg::from(euler.z).0, 45.0, 1e-9);
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        for (order, proper) in orders() {
+            for b in if proper { [0.0, 180.0] } else { [90.0, -90.0] } {
+                let m = Mat3::from_euler(order, Deg(25.0), Deg(b), Deg(-40.0));
+                let angles = m.to_euler(order);
+                let (ra, rb, rc) = degrees(angles);
+                assert_approx_eq!(rb, b, 1e-6);
+                match order {
+                    EulerOrder::Intrinsic(_) => assert_eq!(rc, 0.0),
+                    EulerOrder::Extrinsic(_) => assert_eq!(ra, 0.0),
+                }
+                let r = Mat3::from_euler(order, angles.0, angles.1, angles.2);
+                assert_approx_eq!(r, m, 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{Aabb2, Float, InnerSpace, Segment2, Vec2};
+
+/// Axis-aligned rectangle, the 2D bounding box under the name used by UI and 2D game code
+pub type Rect<S> = Aabb2<S>;
+
+/// Disk given by its center and radius
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Circle<S> {
+    pub center: Vec2<S>,
+    pub radius: S,
+}
+
+impl<S: Float> Circle<S> {
+    #[inline]
+    pub const fn new(center: Vec2<S>, radius: S) -> Circle<S> {
+        Circle { center, radius }
+    }
+
+    #[inline]
+    pub fn area(&self) -> S {
+        S::RAD_HALF_TURN * self.radius * self.radius
+    }
+
+    /// Whether the point is inside the circle or on its boundary
+    #[inline]
+    pub fn contains(&self, point: Vec2<S>) -> bool {
+        (point - self.center).magnitude2() <= self.radius * self.radius
+    }
+
+    /// Whether the circles overlap or touch
+    #[inline]
+    pub fn overlaps(&self, other: &Circle<S>) -> bool {
+        let radius = self.radius + other.radius;
+        (other.center - self.center).magnitude2() <= radius * radius
+    }
+
+    /// Whether the circle overlaps or touches a rectangle
+    #[inline]
+    pub fn overlaps_rect(&self, rect: &Rect<S>) -> bool {
+        rect.squared_distance(self.center) <= self.radius * self.radius
+    }
+
+    /// Smallest rectangle containing the circle
+    #[inline]
+    pub fn aabb(&self) -> Rect<S> {
+        Rect::from_center_extents(self.center, Vec2::new(self.radius, self.radius))
+    }
+}
+
+/// Simple polygon given by its vertices in order, with an implicit edge from the last vertex
+/// back to the first
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon2<S> {
+    pub vertices: Vec<Vec2<S>>,
+}
+
+impl<S: Float> Polygon2<S> {
+    #[inline]
+    pub fn new(vertices: Vec<Vec2<S>>) -> Polygon2<S> {
+        Polygon2 { vertices }
+    }
+
+    /// Edges in order, ending with the edge that closes the polygon
+    pub fn edges(&self) -> impl Iterator<Item = Segment2<S>> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&start, &end)| Segment2::new(start, end))
+    }
+
+    /// Area computed with the shoelace formula, positive if the vertices are in
+    /// counterclockwise order
+    pub fn signed_area(&self) -> S {
+        self.edges()
+            .fold(S::ZERO, |sum, edge| sum + edge.start.perp_dot(edge.end))
+            * S::HALF
+    }
+
+    #[inline]
+    pub fn area(&self) -> S {
+        self.signed_area().abs()
+    }
+
+    /// Whether the vertices are in counterclockwise order
+    #[inline]
+    pub fn is_counterclockwise(&self) -> bool {
+        self.signed_area() > S::ZERO
+    }
+
+    /// Whether the polygon is convex. Collinear vertices are allowed, self-intersecting
+    /// polygons such as stars are not convex
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let (mut positive, mut negative) = (false, false);
+        let mut turning = S::ZERO;
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            let c = self.vertices[(i + 2) % n];
+            let (ab, bc) = (b - a, c - b);
+            let cross = ab.perp_dot(bc);
+            positive |= cross > S::ZERO;
+            negative |= cross < S::ZERO;
+            turning += cross.atan2(ab.dot(bc));
+        }
+        // A convex polygon turns around exactly once
+        !(positive && negative) && (turning.abs() - S::RAD_FULL_TURN).abs() < S::HALF
+    }
+
+    /// Number of times the polygon winds counterclockwise around `point`, negative for
+    /// clockwise windings
+    pub fn winding_number(&self, point: Vec2<S>) -> i32 {
+        self.edges().fold(0, |winding, edge| {
+            let left = (edge.end - edge.start).perp_dot(point - edge.start);
+            if edge.start.y <= point.y {
+                if edge.end.y > point.y && left > S::ZERO {
+                    return winding + 1;
+                }
+            } else if edge.end.y <= point.y && left < S::ZERO {
+                return winding - 1;
+            }
+            winding
+        })
+    }
+
+    /// Whether `point` is inside by the non-zero winding rule. Points exactly on the boundary
+    /// may be reported either way
+    #[inline]
+    pub fn contains(&self, point: Vec2<S>) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    /// Smallest rectangle containing the polygon, or `None` if it has no vertices
+    #[inline]
+    pub fn aabb(&self) -> Option<Rect<S>> {
+        Rect::from_points(self.vertices.iter().copied())
+    }
+
+    /// Part of the polygon inside a rectangle, found with the Sutherland-Hodgman algorithm.
+    /// Clipping a concave polygon that leaves and reenters the rectangle connects the pieces
+    /// with edges along the rectangle boundary
+    pub fn clip_to_rect(&self, rect: &Rect<S>) -> Polygon2<S> {
+        // Each boundary as the signed distance of a point to it, positive inside
+        let boundaries: [&dyn Fn(Vec2<S>) -> S; 4] = [
+            &|p| p.x - rect.min.x,
+            &|p| rect.max.x - p.x,
+            &|p| p.y - rect.min.y,
+            &|p| rect.max.y - p.y,
+        ];
+        let mut vertices = self.vertices.clone();
+        for distance in boundaries {
+            let input = std::mem::take(&mut vertices);
+            let Some(&last) = input.last() else {
+                break;
+            };
+            let mut prev = last;
+            for &current in &input {
+                let (dp, dc) = (distance(prev), distance(current));
+                if (dp >= S::ZERO) != (dc >= S::ZERO) {
+                    vertices.push(prev + (current - prev) * (dp / (dp - dc)));
+                }
+                if dc >= S::ZERO {
+                    vertices.push(current);
+                }
+                prev = current;
+            }
+        }
+        Polygon2::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Circle, Polygon2, Rect, Vec2};
+
+    fn square() -> Polygon2<f64> {
+        Polygon2::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ])
+    }
+
+    /// L-shaped polygon with a notch in the top right
+    fn notched() -> Polygon2<f64> {
+        Polygon2::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(2.0, 4.0),
+            Vec2::new(0.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn test_circle() {
+        let c = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+        assert!(c.contains(Vec2::new(3.0, 1.0)));
+        assert!(!c.contains(Vec2::new(3.0, 2.0)));
+        assert!(c.overlaps(&Circle::new(Vec2::new(5.0, 1.0), 2.0)));
+        assert!(!c.overlaps(&Circle::new(Vec2::new(5.0, 2.0), 2.0)));
+        let rect = Rect::new(Vec2::new(3.0, 3.0), Vec2::new(5.0, 5.0));
+        assert!(!c.overlaps_rect(&rect));
+        assert!(Circle::new(Vec2::new(1.0, 1.0), 3.0).overlaps_rect(&rect));
+        assert_eq!(
+            c.aabb(),
+            Rect::new(Vec2::new(-1.0, -1.0), Vec2::new(3.0, 3.0))
+        );
+        assert_approx_eq!(c.area(), 4.0 * std::f64::consts::PI, 1e-12);
+    }
+
+    #[test]
+    fn test_area_orientation() {
+        let square = square();
+        assert_eq!(square.signed_area(), 4.0);
+        assert!(square.is_counterclockwise());
+        let mut reversed = square.clone();
+        reversed.vertices.reverse();
+        assert_eq!(reversed.signed_area(), -4.0);
+        assert_eq!(reversed.area(), 4.0);
+        assert!(!reversed.is_counterclockwise());
+        assert_eq!(notched().area(), 12.0);
+        assert_eq!(square.edges().count(), 4);
+    }
+
+    #[test]
+    fn test_convex() {
+        assert!(square().is_convex());
+        let mut reversed = square();
+        reversed.vertices.reverse();
+        assert!(reversed.is_convex());
+        assert!(!notched().is_convex());
+        let star = Polygon2::new(
+            (0..5)
+                .map(|i| {
+                    let angle = i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                    Vec2::new(angle.cos(), angle.sin())
+                })
+                .collect(),
+        );
+        assert!(!star.is_convex());
+        assert!(!Polygon2::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)]).is_convex());
+    }
+
+    #[test]
+    fn test_contains() {
+        let notched = notched();
+        assert!(notched.contains(Vec2::new(1.0, 1.0)));
+        assert!(notched.contains(Vec2::new(1.0, 3.0)));
+        assert!(!notched.contains(Vec2::new(3.0, 3.0)));
+        assert!(!notched.contains(Vec2::new(-1.0, 1.0)));
+        assert_eq!(notched.winding_number(Vec2::new(3.0, 1.0)), 1);
+        let mut reversed = notched.clone();
+        reversed.vertices.reverse();
+        assert_eq!(reversed.winding_number(Vec2::new(3.0, 1.0)), -1);
+        assert!(reversed.contains(Vec2::new(3.0, 1.0)));
+    }
+
+    #[test]
+    fn test_clip_to_rect() {
+        let rect = Rect::new(Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0));
+        let clipped = notched().clip_to_rect(&rect);
+        assert_eq!(clipped.area(), 3.0);
+        assert!(clipped.is_counterclockwise());
+        let clipped = square().clip_to_rect(&rect);
+        assert_eq!(clipped.area(), 1.0);
+        let outside = Rect::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0));
+        assert!(square().clip_to_rect(&outside).vertices.is_empty());
+        let around = Rect::new(Vec2::new(-1.0, -1.0), Vec2::new(6.0, 6.0));
+        assert_eq!(square().clip_to_rect(&around), square());
+    }
+}
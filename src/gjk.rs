@@ -0,0 +1,779 @@
+use crate::{
+    Aabb3, Capsule, ConvexHull3, Float, InnerSpace, Mat3, Mat4, Matrix, Obb3, Quat, Sphere,
+    Triangle3, Vec3,
+};
+
+/// Upper bound on GJK iterations, reached only for degenerate or badly scaled shapes
+const GJK_ITERATIONS: usize = 64;
+/// Upper bound on EPA expansions. Curved shapes converge slowly near the contact
+const EPA_ITERATIONS: usize = 128;
+
+/// Convex shape described by its support function, the point of the shape farthest along a
+/// direction. This is all GJK and EPA need to query any pair of convex shapes
+pub trait SupportMap<S: Float> {
+    /// Point of the shape farthest along `dir`, which need not be normalized
+    fn support(&self, dir: Vec3<S>) -> Vec3<S>;
+
+    /// Whether the shapes overlap or touch
+    fn intersects<T: SupportMap<S> + ?Sized>(&self, other: &T) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(gjk(self, other), Gjk::Overlap(_))
+    }
+
+    /// Closest points of this shape and `other`, or `None` if they overlap
+    fn closest_points<T: SupportMap<S> + ?Sized>(&self, other: &T) -> Option<(Vec3<S>, Vec3<S>)>
+    where
+        Self: Sized,
+    {
+        match gjk(self, other) {
+            Gjk::Overlap(_) => None,
+            Gjk::Separated(simplex) => Some(witnesses(&simplex)),
+        }
+    }
+
+    /// Distance between the shapes, zero if they overlap
+    fn distance<T: SupportMap<S> + ?Sized>(&self, other: &T) -> S
+    where
+        Self: Sized,
+    {
+        self.closest_points(other)
+            .map_or(S::ZERO, |(a, b)| (b - a).magnitude())
+    }
+
+    /// Penetration of overlapping shapes found with EPA, or `None` if they are separated
+    fn contact<T: SupportMap<S> + ?Sized>(&self, other: &T) -> Option<Contact<S>>
+    where
+        Self: Sized,
+    {
+        match gjk(self, other) {
+            Gjk::Overlap(simplex) => Some(epa(self, other, simplex)),
+            Gjk::Separated(_) => None,
+        }
+    }
+}
+
+/// Penetration of two overlapping shapes
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Contact<S> {
+    /// Unit direction from the first shape toward the second. Moving the second shape by
+    /// `normal * depth` separates them
+    pub normal: Vec3<S>,
+    pub depth: S,
+    /// Point of the first shape deepest inside the second
+    pub point_a: Vec3<S>,
+    /// Point of the second shape deepest inside the first
+    pub point_b: Vec3<S>,
+}
+
+/// Shape placed in the world by an affine transform, which may rotate, scale and shear
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transformed<T, S> {
+    pub shape: T,
+    pub transform: Mat4<S>,
+}
+
+impl<T, S: Float> Transformed<T, S> {
+    #[inline]
+    pub const fn new(shape: T, transform: Mat4<S>) -> Transformed<T, S> {
+        Transformed { shape, transform }
+    }
+
+    /// Place the shape with a rigid transform. Assumes a normalized rotation quaternion
+    #[inline]
+    pub fn from_rotation_translation(
+        shape: T,
+        rotation: Quat<S>,
+        translation: Vec3<S>,
+    ) -> Transformed<T, S> {
+        let scale = Vec3::new(S::ONE, S::ONE, S::ONE);
+        Transformed::new(
+            shape,
+            Mat4::from_scale_quaternion_translation(scale, rotation, translation),
+        )
+    }
+}
+
+impl<S: Float, T: SupportMap<S> + ?Sized> SupportMap<S> for &T {
+    #[inline]
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        (**self).support(dir)
+    }
+}
+
+impl<S: Float, T: SupportMap<S>> SupportMap<S> for Transformed<T, S> {
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        // The farthest point of the image along `dir` is the image of the farthest point
+        // along the direction mapped back by the transposed linear part
+        let local = Mat3::from(self.transform).transpose() * dir;
+        self.transform.transform_point3(self.shape.support(local))
+    }
+}
+
+impl<S: Float> SupportMap<S> for Sphere<S> {
+    #[inline]
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        let length = dir.magnitude();
+        if length > S::ZERO {
+            self.center + dir * (self.radius / length)
+        } else {
+            self.center
+        }
+    }
+}
+
+impl<S: Float> SupportMap<S> for Capsule<S> {
+    #[inline]
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        let (start, end) = (self.segment.start, self.segment.end);
+        let center = if dir.dot(end) > dir.dot(start) {
+            end
+        } else {
+            start
+        };
+        Sphere::new(center, self.radius).support(dir)
+    }
+}
+
+/// Corner offset selected by the signs of `dir`
+#[inline]
+fn signed_extents<S: Float>(extents: Vec3<S>, dir: Vec3<S>) -> Vec3<S> {
+    let pick = |e: S, d: S| if d < S::ZERO { -e } else { e };
+    Vec3::new(
+        pick(extents.x, dir.x),
+        pick(extents.y, dir.y),
+        pick(extents.z, dir.z),
+    )
+}
+
+impl<S: Float> SupportMap<S> for Aabb3<S> {
+    #[inline]
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        let center = (self.min + self.max) * S::HALF;
+        center + signed_extents((self.max - self.min) * S::HALF, dir)
+    }
+}
+
+impl<S: Float> SupportMap<S> for Obb3<S> {
+    #[inline]
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        let local = self.rotation.transpose() * dir;
+        self.center + self.rotation * signed_extents(self.extents, local)
+    }
+}
+
+impl<S: Float> SupportMap<S> for ConvexHull3<S> {
+    fn support(&self, dir: Vec3<S>) -> Vec3<S> {
+        self.vertices
+            .iter()
+            .copied()
+            .reduce(|best, v| if v.dot(dir) > best.dot(dir) { v } else { best })
+            .expect("convex hull has vertices")
+    }
+}
+
+/// Point of the Minkowski difference `a - b` together with the shape points producing it
+#[derive(Copy, Clone, Debug)]
+struct Vertex<S> {
+    w: Vec3<S>,
+    a: Vec3<S>,
+    b: Vec3<S>,
+}
+
+impl<S: Float> Vertex<S> {
+    #[inline]
+    fn support<A, B>(a: &A, b: &B, dir: Vec3<S>) -> Vertex<S>
+    where
+        A: SupportMap<S> + ?Sized,
+        B: SupportMap<S> + ?Sized,
+    {
+        let (a, b) = (a.support(dir), b.support(-dir));
+        Vertex { w: a - b, a, b }
+    }
+}
+
+/// Simplex vertices with the barycentric weights of the point closest to the origin
+type Simplex<S> = Vec<(Vertex<S>, S)>;
+
+enum Gjk<S> {
+    /// Vertices of a simplex containing the origin
+    Overlap(Vec<Vertex<S>>),
+    /// Face of the Minkowski difference closest to the origin
+    Separated(Simplex<S>),
+}
+
+#[inline]
+fn closest<S: Float>(simplex: &Simplex<S>) -> Vec3<S> {
+    simplex
+        .iter()
+        .fold(Vec3::new(S::ZERO, S::ZERO, S::ZERO), |sum, &(v, t)| {
+            sum + v.w * t
+        })
+}
+
+#[inline]
+fn witnesses<S: Float>(simplex: &Simplex<S>) -> (Vec3<S>, Vec3<S>) {
+    let zero = Vec3::new(S::ZERO, S::ZERO, S::ZERO);
+    simplex
+        .iter()
+        .fold((zero, zero), |(a, b), &(v, t)| (a + v.a * t, b + v.b * t))
+}
+
+fn closest_on_segment<S: Float>(a: Vertex<S>, b: Vertex<S>) -> Simplex<S> {
+    let ab = b.w - a.w;
+    let length2 = ab.dot(ab);
+    if length2 == S::ZERO {
+        return vec![(a, S::ONE)];
+    }
+    let t = -a.w.dot(ab) / length2;
+    if t <= S::ZERO {
+        vec![(a, S::ONE)]
+    } else if t >= S::ONE {
+        vec![(b, S::ONE)]
+    } else {
+        vec![(a, S::ONE - t), (b, t)]
+    }
+}
+
+/// Closest feature of the triangle to the origin by Voronoi regions, following Ericson's
+/// Real-Time Collision Detection
+fn closest_on_triangle<S: Float>(a: Vertex<S>, b: Vertex<S>, c: Vertex<S>) -> Simplex<S> {
+    let (ab, ac) = (b.w - a.w, c.w - a.w);
+    let (d1, d2) = (-ab.dot(a.w), -ac.dot(a.w));
+    if d1 <= S::ZERO && d2 <= S::ZERO {
+        return vec![(a, S::ONE)];
+    }
+    let (d3, d4) = (-ab.dot(b.w), -ac.dot(b.w));
+    if d3 >= S::ZERO && d4 <= d3 {
+        return vec![(b, S::ONE)];
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= S::ZERO && d1 >= S::ZERO && d3 <= S::ZERO {
+        let t = d1 / (d1 - d3);
+        return vec![(a, S::ONE - t), (b, t)];
+    }
+    let (d5, d6) = (-ab.dot(c.w), -ac.dot(c.w));
+    if d6 >= S::ZERO && d5 <= d6 {
+        return vec![(c, S::ONE)];
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= S::ZERO && d2 >= S::ZERO && d6 <= S::ZERO {
+        let t = d2 / (d2 - d6);
+        return vec![(a, S::ONE - t), (c, t)];
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= S::ZERO && d4 >= d3 && d5 >= d6 {
+        let t = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return vec![(b, S::ONE - t), (c, t)];
+    }
+    let denom = (va + vb + vc).recip();
+    let (v, w) = (vb * denom, vc * denom);
+    vec![(a, S::ONE - v - w), (b, v), (c, w)]
+}
+
+/// Closest feature of the tetrahedron to the origin, or `None` if the origin is inside
+fn closest_on_tetrahedron<S: Float>(vertices: [Vertex<S>; 4]) -> Option<Simplex<S>> {
+    let faces = [[0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2], [1, 3, 2, 0]];
+    let scale = vertices
+        .iter()
+        .fold(S::ZERO, |scale, vertex| scale.max(vertex.w.magnitude2()));
+    let mut best: Option<(Simplex<S>, S)> = None;
+    for [i, j, k, opposite] in faces {
+        let (a, b, c) = (vertices[i], vertices[j], vertices[k]);
+        let (ab, ac) = (b.w - a.w, c.w - a.w);
+        let normal = ab.cross(ac);
+        // The origin can only be closest to faces whose plane separates it from the opposite
+        // vertex. An origin within rounding of the plane touches the face, but if the opposite
+        // vertex is that close the tetrahedron is flat there and the face has to be checked
+        let origin = -normal.dot(a.w);
+        let other = normal.dot(vertices[opposite].w - a.w);
+        let error = rounding::<S>() * scale * (ab.magnitude() + ac.magnitude());
+        if other.abs() > error && (origin.abs() <= error || origin * other > S::ZERO) {
+            continue;
+        }
+        let simplex = closest_on_triangle(a, b, c);
+        let distance = closest(&simplex).magnitude2();
+        if best.as_ref().is_none_or(|&(_, d)| distance < d) {
+            best = Some((simplex, distance));
+        }
+    }
+    best.map(|(simplex, _)| simplex)
+}
+
+/// Gilbert-Johnson-Keerthi distance algorithm on the Minkowski difference `a - b`
+fn gjk<S, A, B>(a: &A, b: &B) -> Gjk<S>
+where
+    S: Float,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    let tolerance = S::EPSILON.sqrt();
+    let rounding = rounding::<S>();
+    let mut simplex: Simplex<S> = vec![(Vertex::support(a, b, Vec3::X), S::ONE)];
+    let mut v = simplex[0].0.w;
+    for _ in 0..GJK_ITERATIONS {
+        let vv = v.magnitude2();
+        let scale = simplex.iter().fold(S::ZERO, |scale, (vertex, _)| {
+            scale.max(vertex.w.magnitude2())
+        });
+        // The origin is on the simplex up to rounding, the shapes touch or overlap
+        if vv <= rounding * rounding * scale {
+            return Gjk::Overlap(simplex.into_iter().map(|(vertex, _)| vertex).collect());
+        }
+        let w = Vertex::support(a, b, -v);
+        // No support point is meaningfully closer, so `v` is the closest point
+        if vv - v.dot(w.w) <= tolerance * vv {
+            return Gjk::Separated(simplex);
+        }
+        let vertices: Vec<Vertex<S>> = simplex.iter().map(|&(vertex, _)| vertex).collect();
+        let next = match *vertices.as_slice() {
+            [p] => closest_on_segment(p, w),
+            [p, q] => closest_on_triangle(p, q, w),
+            [p, q, r] => match closest_on_tetrahedron([p, q, r, w]) {
+                Some(next) => next,
+                None => return Gjk::Overlap(vec![p, q, r, w]),
+            },
+            _ => unreachable!("the simplex has at most three vertices between iterations"),
+        };
+        let next_v = closest(&next);
+        // Rounding stalls progress once the distance is resolved
+        if next_v.magnitude2() >= vv {
+            return Gjk::Separated(simplex);
+        }
+        simplex = next;
+        v = next_v;
+    }
+    Gjk::Separated(simplex)
+}
+
+/// Relative rounding error of points combined from a few support points
+#[inline]
+fn rounding<S: Float>() -> S {
+    (S::ONE + S::ONE) * (S::ONE + S::ONE) * S::EPSILON
+}
+
+/// Face of the expanding polytope with an outward unit normal
+#[derive(Copy, Clone, Debug)]
+struct Face<S> {
+    indices: [usize; 3],
+    normal: Vec3<S>,
+    distance: S,
+}
+
+impl<S: Float> Face<S> {
+    /// Face oriented away from `inside`, or `None` if it is degenerate
+    fn new(vertices: &[Vertex<S>], [i, j, k]: [usize; 3], inside: Vec3<S>) -> Option<Face<S>> {
+        let (a, b, c) = (vertices[i].w, vertices[j].w, vertices[k].w);
+        let normal = (b - a).cross(c - a);
+        let length = normal.magnitude();
+        if length == S::ZERO {
+            return None;
+        }
+        let normal = normal / length;
+        let (indices, normal) = if normal.dot(a - inside) < S::ZERO {
+            ([i, k, j], -normal)
+        } else {
+            ([i, j, k], normal)
+        };
+        Some(Face {
+            indices,
+            normal,
+            distance: normal.dot(a),
+        })
+    }
+
+    /// Whether the face has the directed edge from `p` to `q`
+    fn has_edge(&self, p: usize, q: usize) -> bool {
+        let [i, j, k] = self.indices;
+        [[i, j], [j, k], [k, i]].contains(&[p, q])
+    }
+}
+
+/// Faces of the polytope that `point` sees, flood filled from `start`, and the edges of the
+/// horizon around them. Faces whose plane passes within the tolerance of the point count as
+/// seen, so that no sliver faces are built on their edges. Returns `None` if the horizon is
+/// not a single loop, which only rounding can cause
+fn visible_region<S: Float>(
+    faces: &[Face<S>],
+    start: usize,
+    point: Vec3<S>,
+    tolerance: S,
+) -> Option<(Vec<bool>, Vec<[usize; 2]>)> {
+    let mut visible = vec![false; faces.len()];
+    visible[start] = true;
+    let mut stack = vec![start];
+    let mut horizon = Vec::new();
+    while let Some(f) = stack.pop() {
+        let [i, j, k] = faces[f].indices;
+        for [p, q] in [[i, j], [j, k], [k, i]] {
+            let neighbor = faces.iter().position(|face| face.has_edge(q, p))?;
+            if visible[neighbor] {
+                continue;
+            }
+            let face = &faces[neighbor];
+            if face.normal.dot(point) - face.distance > -tolerance {
+                visible[neighbor] = true;
+                stack.push(neighbor);
+            } else {
+                horizon.push([p, q]);
+            }
+        }
+    }
+    // Walk along the horizon, it has to come back to the start only after using every edge
+    let [first, mut vertex] = *horizon.first()?;
+    let mut length = 1;
+    while vertex != first && length < horizon.len() {
+        vertex = horizon.iter().find(|edge| edge[0] == vertex)?[1];
+        length += 1;
+    }
+    (vertex == first && length == horizon.len()).then_some((visible, horizon))
+}
+
+/// Grow the simplex from GJK into a tetrahedron in the Minkowski difference that still contains
+/// the origin, or `None` if the difference is flat
+fn tetrahedron<S, A, B>(
+    a: &A,
+    b: &B,
+    mut simplex: Vec<Vertex<S>>,
+    tolerance: S,
+) -> Option<[Vertex<S>; 4]>
+where
+    S: Float,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    // First support point along `dirs` that moves far enough off the current simplex
+    let grow = |dirs: &[Vec3<S>], offset: &dyn Fn(Vec3<S>) -> S| {
+        dirs.iter()
+            .map(|&dir| Vertex::support(a, b, dir))
+            .find(|vertex| offset(vertex.w) > tolerance)
+    };
+    if simplex.len() == 1 {
+        let p = simplex[0].w;
+        let axes = [Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y, Vec3::Z, -Vec3::Z];
+        simplex.push(grow(&axes, &|w| (w - p).magnitude())?);
+    }
+    if simplex.len() == 2 {
+        let (p, q) = (simplex[0].w, simplex[1].w);
+        let axis = (q - p).normalize();
+        let u = axis.any_orthonormal();
+        let v = axis.cross(u);
+        let dirs = [u, -u, v, -v];
+        let off_line = |w: Vec3<S>| {
+            let d = w - p;
+            (d - axis * d.dot(axis)).magnitude()
+        };
+        simplex.push(grow(&dirs, &off_line)?);
+    }
+    if simplex.len() == 3 {
+        let p = simplex[0].w;
+        let normal = (simplex[1].w - p).cross(simplex[2].w - p).normalize();
+        simplex.push(grow(&[normal, -normal], &|w| normal.dot(w - p).abs())?);
+    }
+    Some([simplex[0], simplex[1], simplex[2], simplex[3]])
+}
+
+/// Contact from a face of the Minkowski difference, taking the witness points at the
+/// projection of the origin
+fn face_contact<S: Float>(vertices: &[Vertex<S>], face: &Face<S>) -> Contact<S> {
+    let [a, b, c] = face.indices.map(|i| vertices[i]);
+    let projection = face.normal * face.distance;
+    let third = S::ONE / (S::ONE + S::ONE + S::ONE);
+    let weights = Triangle3::new(a.w, b.w, c.w)
+        .barycentric(projection)
+        .unwrap_or(Vec3::new(third, third, third));
+    let simplex = vec![(a, weights.x), (b, weights.y), (c, weights.z)];
+    let (point_a, point_b) = witnesses(&simplex);
+    Contact {
+        normal: face.normal,
+        depth: face.distance.max(S::ZERO),
+        point_a,
+        point_b,
+    }
+}
+
+/// Expanding polytope algorithm, pushing the faces of the Minkowski difference around the
+/// origin outward until the closest one lies on its boundary
+fn epa<S, A, B>(a: &A, b: &B, simplex: Vec<Vertex<S>>) -> Contact<S>
+where
+    S: Float,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    let scale = simplex
+        .iter()
+        .fold(S::ZERO, |scale, vertex| scale.max(vertex.w.magnitude()));
+    let tolerance = S::EPSILON.sqrt() * scale.max(S::EPSILON);
+    // Shapes without volume only touch, so any normal separates them immediately
+    let flat = Contact {
+        normal: Vec3::X,
+        depth: S::ZERO,
+        point_a: simplex[0].a,
+        point_b: simplex[0].b,
+    };
+    let Some(initial) = tetrahedron(a, b, simplex, tolerance) else {
+        return flat;
+    };
+    let mut vertices = initial.to_vec();
+    let inside = vertices
+        .iter()
+        .fold(Vec3::new(S::ZERO, S::ZERO, S::ZERO), |sum, v| sum + v.w)
+        * (S::ONE / (S::ONE + S::ONE + S::ONE + S::ONE));
+    let faces: Option<Vec<Face<S>>> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .into_iter()
+        .map(|indices| Face::new(&vertices, indices, inside))
+        .collect();
+    let Some(mut faces) = faces else {
+        return flat;
+    };
+    // Every support query gives the exact depth along its direction, an upper bound on the
+    // penetration. The smallest one stands in if the polytope stops converging
+    let mut upper: Option<(Vec3<S>, Vertex<S>, S)> = None;
+    for _ in 0..EPA_ITERATIONS {
+        let (nearest, closest) = faces
+            .iter()
+            .copied()
+            .enumerate()
+            .reduce(|best, face| {
+                if face.1.distance < best.1.distance {
+                    face
+                } else {
+                    best
+                }
+            })
+            .expect("the polytope has faces");
+        let point = Vertex::support(a, b, closest.normal);
+        let depth = closest.normal.dot(point.w);
+        if depth - closest.distance <= tolerance {
+            return face_contact(&vertices, &closest);
+        }
+        if upper.is_none_or(|(_, _, best)| depth < best) {
+            upper = Some((closest.normal, point, depth));
+        }
+        let Some((visible, horizon)) = visible_region(&faces, nearest, point.w, tolerance) else {
+            break;
+        };
+        let index = vertices.len();
+        vertices.push(point);
+        let patch: Option<Vec<Face<S>>> = horizon
+            .iter()
+            .map(|&[i, j]| Face::new(&vertices, [i, j, index], inside))
+            .collect();
+        let Some(patch) = patch else {
+            break;
+        };
+        let mut visible = visible.into_iter();
+        faces.retain(|_| !visible.next().unwrap_or(false));
+        faces.extend(patch);
+    }
+    // Rounding left the polytope unable to grow. Rather than report a face that is not on the
+    // boundary, fall back to the shallowest direction seen, which still separates the shapes
+    let (normal, point, depth) = upper.expect("the closest face is checked before growing");
+    Contact {
+        normal,
+        depth: depth.max(S::ZERO),
+        point_a: point.a,
+        point_b: point.b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Aabb3, Capsule, ConvexHull3, Deg, InnerSpace, Mat4, Obb3, Quat, Rad, Sphere, SupportMap,
+        Transformed, Vec3,
+    };
+
+    fn unit_box(center: Vec3<f64>) -> Aabb3<f64> {
+        let half = Vec3::new(1.0, 1.0, 1.0);
+        Aabb3::new(center - half, center + half)
+    }
+
+    #[test]
+    fn test_support() {
+        let sphere = Sphere::new(Vec3::new(1.0, 0.0, 0.0), 2.0);
+        assert_approx_eq!(
+            sphere.support(Vec3::new(0.0, 3.0, 0.0)),
+            Vec3::new(1.0, 2.0, 0.0),
+            1e-12
+        );
+        let aabb = unit_box(Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(
+            aabb.support(Vec3::new(1.0, -2.0, 3.0)),
+            Vec3::new(1.0, -1.0, 1.0)
+        );
+        let obb = Obb3::from_quat(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 1.0, 1.0),
+            Quat::from_rotation_z(Deg(90.0)),
+        );
+        assert_approx_eq!(
+            obb.support(Vec3::new(0.1, 1.0, 0.1)),
+            Vec3::new(1.0, 2.0, 1.0),
+            1e-12
+        );
+        let capsule = Capsule::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0), 1.0);
+        assert_approx_eq!(capsule.support(Vec3::Y), Vec3::new(0.0, 5.0, 0.0), 1e-12);
+        let scaled = Transformed::new(sphere, Mat4::from_nonuniform_scale(1.0, 3.0, 1.0));
+        assert_approx_eq!(scaled.support(Vec3::Y), Vec3::new(1.0, 6.0, 0.0), 1e-12);
+    }
+
+    #[test]
+    fn test_sphere_distance() {
+        let a = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(Vec3::new(3.0, 4.0, 0.0), 2.0);
+        assert!(!a.intersects(&b));
+        assert_approx_eq!(a.distance(&b), 2.0, 1e-6);
+        let (pa, pb) = a.closest_points(&b).unwrap();
+        assert_approx_eq!(pa, Vec3::new(0.6, 0.8, 0.0), 1e-4);
+        assert_approx_eq!(pb, Vec3::new(1.8, 2.4, 0.0), 1e-4);
+        assert_eq!(a.contact(&b), None);
+    }
+
+    #[test]
+    fn test_sphere_contact() {
+        let a = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(Vec3::new(1.5, 0.0, 0.0), 1.0);
+        assert!(a.intersects(&b));
+        assert_eq!(a.distance(&b), 0.0);
+        assert_eq!(a.closest_points(&b), None);
+        let contact = a.contact(&b).unwrap();
+        assert_approx_eq!(contact.normal, Vec3::X, 5e-4);
+        assert_approx_eq!(contact.depth, 0.5, 1e-7);
+        assert_approx_eq!(contact.point_a, Vec3::new(1.0, 0.0, 0.0), 1e-4);
+        assert_approx_eq!(contact.point_b, Vec3::new(0.5, 0.0, 0.0), 1e-4);
+    }
+
+    #[test]
+    fn test_box_contact() {
+        let a = unit_box(Vec3::new(0.0, 0.0, 0.0));
+        let b = unit_box(Vec3::new(0.2, 1.7, -0.3));
+        let contact = a.contact(&b).unwrap();
+        assert_approx_eq!(contact.normal, Vec3::Y, 1e-9);
+        assert_approx_eq!(contact.depth, 0.3, 1e-9);
+        // Moving the second box out along the normal leaves them touching
+        let moved = unit_box(Vec3::new(0.2, 1.7, -0.3) + contact.normal * (contact.depth + 1e-6));
+        assert!(!a.intersects(&moved));
+        let far = unit_box(Vec3::new(4.0, 0.5, 0.0));
+        assert_approx_eq!(a.distance(&far), 2.0, 1e-9);
+        let (pa, pb) = a.closest_points(&far).unwrap();
+        assert_approx_eq!(pa.x, 1.0, 1e-9);
+        assert_approx_eq!(pb.x, 3.0, 1e-9);
+        assert_approx_eq!(pa.y, pb.y, 1e-9);
+    }
+
+    #[test]
+    fn test_box_contact_deep() {
+        // EPA has to expand well past its first faces to find the shallowest axis
+        let a = Aabb3::new(
+            Vec3::new(-4.459928243153714, -0.23820131679668877, -2.163320874899851),
+            Vec3::new(-1.077831824889544, 3.753437714072348, 1.5319065256937847),
+        );
+        let b = Aabb3::new(
+            Vec3::new(-3.826112062362884, 2.1986513150517193, -1.2141114450909614),
+            Vec3::new(-0.7823554738116141, 3.560336302808827, -0.7041897616099257),
+        );
+        let contact = a.contact(&b).unwrap();
+        assert_approx_eq!(contact.normal, -Vec3::Z, 1e-9);
+        assert_approx_eq!(contact.depth, 1.4591311132899252, 1e-9);
+    }
+
+    #[test]
+    fn test_f32() {
+        let a = Aabb3::from_center_extents(
+            Vec3::new(-0.616858f32, 0.9517114, 1.092628),
+            Vec3::new(1.0, 1.0, 1.0),
+        );
+        let b = Aabb3::from_center_extents(
+            Vec3::new(-1.1708604f32, 1.3110614, 0.37164068),
+            Vec3::new(1.0, 0.5, 2.0),
+        );
+        assert!(a.intersects(&b));
+        let contact = a.contact(&b).unwrap();
+        assert_approx_eq!(contact.normal, Vec3::Y, 1e-5);
+        assert_approx_eq!(contact.depth, 1.14065, 1e-5);
+        // Separated by a gap of 1.36e-4 along z
+        let a = Aabb3::new(
+            Vec3::new(-2.046614f32, -2.476268, -3.661996),
+            Vec3::new(1.920686, -0.79113203, 0.31843603),
+        );
+        let b = Aabb3::new(
+            Vec3::new(0.72116196f32, -3.223744, 0.31857204),
+            Vec3::new(1.116318, 0.38019192, 2.518596),
+        );
+        assert!(!a.intersects(&b));
+        assert_approx_eq!(a.distance(&b), 1.36e-4, 1e-6);
+        let a = Sphere::new(Vec3::new(0.426456f32, 1.394156, -1.490428), 0.930896);
+        let b = Sphere::new(Vec3::new(0.614340f32, 0.965192, -0.142564), 1.538512);
+        assert!(a.intersects(&b));
+        assert_approx_eq!(a.contact(&b).unwrap().depth, 1.04249, 1e-3);
+    }
+
+    #[test]
+    fn test_resting_contact() {
+        // A sphere resting just off a face of a turned box, placed over a grid on the face
+        let extents = Vec3::new(1.0, 0.7, 0.5);
+        for (i, axis) in [Vec3::X, Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)]
+            .into_iter()
+            .enumerate()
+        {
+            let rotation = Quat::from_axis_angle(axis.normalize(), Rad(0.3 + i as f64));
+            let obb = Obb3::from_quat(Vec3::new(0.0, 0.0, 0.0), extents, rotation);
+            for j in 0..400 {
+                let y = (j % 20) as f64 / 20.0 - 0.5;
+                let z = (j / 20) as f64 / 20.0 - 0.5;
+                for gap in [1e-5, -1e-5] {
+                    let local = Vec3::new(extents.x + 0.8 + gap, y * extents.y, z * extents.z);
+                    let sphere = Sphere::new(rotation * local, 0.8);
+                    assert_eq!(obb.intersects(&sphere), gap < 0.0);
+                    if gap > 0.0 {
+                        assert_approx_eq!(obb.distance(&sphere), gap, 1e-9);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transformed() {
+        // A unit cube turned 45 degrees reaches sqrt(2) along x
+        let turned = Transformed::from_rotation_translation(
+            unit_box(Vec3::new(0.0, 0.0, 0.0)),
+            Quat::from_rotation_z(Deg(45.0)),
+            Vec3::new(0.0, 0.0, 0.0),
+        );
+        let sphere = Sphere::new(Vec3::new(3.0, 0.0, 0.0), 1.0);
+        assert_approx_eq!(turned.distance(&sphere), 2.0 - 2f64.sqrt(), 1e-6);
+        // The sphere reaches the vertical edge of the cube at x = sqrt(2)
+        let sphere = Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0);
+        let contact = sphere.contact(&turned).unwrap();
+        assert_approx_eq!(contact.normal, -Vec3::X, 1e-4);
+        assert_approx_eq!(contact.depth, 2f64.sqrt() - 1.0, 1e-7);
+        assert_approx_eq!(contact.point_b, Vec3::new(2f64.sqrt(), 0.0, 0.0), 2e-4);
+    }
+
+    #[test]
+    fn test_hull_and_capsule() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 0.0, 2.0),
+        ];
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        let capsule = Capsule::new(Vec3::new(3.0, 3.0, -1.0), Vec3::new(3.0, 3.0, 1.0), 0.5);
+        // The hull edge from (2, 0, 0) to (0, 2, 0) is closest to the capsule axis at (3, 3, 0)
+        let expected = 2.0 * 2f64.sqrt() - 0.5;
+        assert_approx_eq!(hull.distance(&capsule), expected, 1e-6);
+        let inside = Capsule::new(Vec3::new(0.5, 0.5, -1.0), Vec3::new(0.5, 0.5, 1.0), 0.1);
+        let contact = hull.contact(&inside).unwrap();
+        assert!(contact.depth > 0.0);
+        let moved = Capsule::new(
+            inside.segment.start + contact.normal * (contact.depth + 1e-6),
+            inside.segment.end + contact.normal * (contact.depth + 1e-6),
+            0.1,
+        );
+        assert!(!hull.intersects(&moved));
+    }
+}
@@ -0,0 +1,616 @@
+use std::cmp::Ordering;
+
+use crate::predicates::orientation;
+use crate::{Float, InnerSpace, Polygon2, Triangle3, Vec2, Vec3};
+
+impl<S: Float> Polygon2<S> {
+    /// Convex hull of a point set with Andrew's monotone chain algorithm, in counterclockwise
+    /// order starting from the lowest leftmost point. Duplicate points and points on the hull
+    /// edges are dropped, so fewer than three vertices are left when all points are collinear
+    pub fn convex_hull(points: &[Vec2<S>]) -> Polygon2<S> {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            let x = a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal);
+            x.then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+        });
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Polygon2::new(sorted);
+        }
+
+        let turns_left = |a: Vec2<S>, b: Vec2<S>, c: Vec2<S>| (b - a).perp_dot(c - a) > S::ZERO;
+        let mut hull: Vec<Vec2<S>> = Vec::with_capacity(sorted.len() + 1);
+        let push = |hull: &mut Vec<Vec2<S>>, floor: usize, p: Vec2<S>| {
+            while hull.len() >= floor + 2
+                && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], p)
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        };
+        // Lower chain from left to right, then the upper chain back
+        for &p in &sorted {
+            push(&mut hull, 0, p);
+        }
+        let floor = hull.len() - 1;
+        for &p in sorted.iter().rev().skip(1) {
+            push(&mut hull, floor, p);
+        }
+        hull.pop();
+        Polygon2::new(hull)
+    }
+}
+
+/// Convex polyhedron built around a point set, see `ConvexHull3::from_points`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexHull3<S> {
+    /// Points on the hull
+    pub vertices: Vec<Vec3<S>>,
+    /// Index of each vertex in the input points
+    pub indices: Vec<usize>,
+    /// Triangles indexing into `vertices`, counterclockwise when seen from outside
+    pub faces: Vec<[usize; 3]>,
+}
+
+/// Face of a hull under construction, with the points still outside of it
+struct Face<S> {
+    vertices: [usize; 3],
+    /// Faces across the edges starting at each vertex
+    neighbors: [usize; 3],
+    normal: Vec3<S>,
+    offset: S,
+    outside: Vec<usize>,
+    alive: bool,
+    /// Last iteration in which the face was found visible from the eye point
+    visited: usize,
+}
+
+impl<S: Float> Face<S> {
+    fn new(vertices: [usize; 3], neighbors: [usize; 3], points: &[Vec3<S>]) -> Face<S> {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        let normal = (b - a).cross(c - a).normalize();
+        Face {
+            vertices,
+            neighbors,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            alive: true,
+            visited: 0,
+        }
+    }
+
+    #[inline]
+    fn distance(&self, point: Vec3<S>) -> S {
+        self.normal.dot(point) - self.offset
+    }
+
+    /// Whether the point is strictly in front of the face, decided exactly
+    #[inline]
+    fn above(&self, point: Vec3<S>, points: &[Vec3<S>]) -> bool {
+        let [a, b, c] = self.vertices.map(|i| points[i]);
+        orientation(a, b, c, point) == Ordering::Greater
+    }
+
+    /// Index of the edge going from `a` to `b`
+    fn edge(&self, a: usize, b: usize) -> Option<usize> {
+        (0..3).find(|&k| self.vertices[k] == a && self.vertices[(k + 1) % 3] == b)
+    }
+}
+
+impl<S: Float> ConvexHull3<S> {
+    /// Convex hull of a point set with the quickhull algorithm. Which side of a face a point
+    /// lies on is decided exactly, so the hull stays convex however close to coplanar the
+    /// points are. Points closer to the hull than a tolerance derived from the extent of the
+    /// input are treated as lying on it, which makes duplicate and coplanar points safe.
+    /// Vertices within the tolerance of a hull edge are dropped as well.
+    /// Returns `None` if there are fewer than four points that are not coplanar
+    pub fn from_points(points: &[Vec3<S>]) -> Option<ConvexHull3<S>> {
+        let extent = points.iter().fold(S::ZERO, |extent, p| {
+            extent.max(p.x.abs()).max(p.y.abs()).max(p.z.abs())
+        });
+        let three = S::ONE + S::ONE + S::ONE;
+        let tolerance = three * (extent + extent + extent) * S::EPSILON;
+
+        let mut faces = quickhull(points, tolerance)?;
+        // A vertex may be picked as an eye point before the hull grows past it and leaves it
+        // on an edge. Dropping it changes the shape only within the tolerance, so rebuild
+        // from the remaining vertices until there is nothing left to drop
+        loop {
+            let mut indices: Vec<usize> = faces.iter().flatten().copied().collect();
+            indices.sort_unstable();
+            indices.dedup();
+            // Vertices joined to each vertex by a hull edge
+            let mut links = vec![Vec::new(); points.len()];
+            for &[a, b, c] in &faces {
+                links[a].push(b);
+                links[b].push(c);
+                links[c].push(a);
+            }
+            let kept: Vec<usize> = indices
+                .iter()
+                .copied()
+                .filter(|&v| !on_edge(points[v], &links[v], points, tolerance))
+                .collect();
+            if kept.len() == indices.len() {
+                let remap = |i: usize| indices.binary_search(&i).unwrap();
+                return Some(ConvexHull3 {
+                    vertices: indices.iter().map(|&i| points[i]).collect(),
+                    faces: faces.iter().map(|f| f.map(remap)).collect(),
+                    indices,
+                });
+            }
+            let subset: Vec<Vec3<S>> = kept.iter().map(|&i| points[i]).collect();
+            faces = quickhull(&subset, tolerance)?
+                .into_iter()
+                .map(|f| f.map(|i| kept[i]))
+                .collect();
+        }
+    }
+
+    /// Outward unit normal of a face
+    pub fn face_normal(&self, face: usize) -> Vec3<S> {
+        let [a, b, c] = self.faces[face].map(|i| self.vertices[i]);
+        (b - a).cross(c - a).normalize()
+    }
+
+    /// Whether the point is inside the hull or on its boundary
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        (0..self.faces.len()).all(|f| {
+            let a = self.vertices[self.faces[f][0]];
+            self.face_normal(f).dot(point - a) <= S::ZERO
+        })
+    }
+
+    pub fn volume(&self) -> S {
+        let origin = self.vertices[0];
+        let six = (S::ONE + S::ONE) * (S::ONE + S::ONE + S::ONE);
+        self.faces.iter().fold(S::ZERO, |sum, f| {
+            let [a, b, c] = f.map(|i| self.vertices[i] - origin);
+            sum + a.dot(b.cross(c))
+        }) / six
+    }
+}
+
+/// Faces of the convex hull of the points, as indices into `points`
+fn quickhull<S: Float>(points: &[Vec3<S>], tolerance: S) -> Option<Vec<[usize; 3]>> {
+    let mut faces = initial_simplex(points, tolerance)?;
+    let simplex = [0, 1, 2, 3];
+    for i in 0..points.len() {
+        assign(&mut faces, &simplex, i, points, tolerance);
+    }
+
+    // Slots of deleted faces, reused by new ones
+    let mut free = Vec::new();
+    let mut pending: Vec<usize> = simplex.to_vec();
+    let mut iteration = 0;
+    let mut visible = Vec::new();
+    let mut horizon = Vec::new();
+    // Faces of the flood fill with the next edge to cross and the number of edges left
+    let mut stack: Vec<(usize, usize, usize)> = Vec::new();
+    let mut created = Vec::new();
+    while let Some(f) = pending.pop() {
+        if !faces[f].alive || faces[f].outside.is_empty() {
+            continue;
+        }
+        let eye_index = faces[f]
+            .outside
+            .iter()
+            .copied()
+            .max_by(|&a, &b| {
+                let (da, db) = (faces[f].distance(points[a]), faces[f].distance(points[b]));
+                da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        let eye = points[eye_index];
+
+        // Flood fill the faces visible from the eye, starting at the face it was found for.
+        // Walking the edges of each face in order collects the horizon as a closed loop of
+        // counterclockwise edges, each with the hidden face behind it
+        iteration += 1;
+        visible.clear();
+        horizon.clear();
+        faces[f].visited = iteration;
+        visible.push(f);
+        stack.push((f, 0, 3));
+        while let Some(top) = stack.last_mut() {
+            let (face, edge, remaining) = *top;
+            if remaining == 0 {
+                stack.pop();
+                continue;
+            }
+            *top = (face, (edge + 1) % 3, remaining - 1);
+            let neighbor = faces[face].neighbors[edge];
+            if faces[neighbor].visited == iteration {
+                continue;
+            }
+            let (a, b) = (
+                faces[face].vertices[edge],
+                faces[face].vertices[(edge + 1) % 3],
+            );
+            if faces[neighbor].above(eye, points) {
+                faces[neighbor].visited = iteration;
+                visible.push(neighbor);
+                let entry = faces[neighbor].edge(b, a).unwrap();
+                stack.push((neighbor, (entry + 1) % 3, 2));
+            } else {
+                horizon.push((a, b, neighbor));
+            }
+        }
+
+        let mut orphans = Vec::new();
+        for &i in &visible {
+            faces[i].alive = false;
+            orphans.append(&mut faces[i].outside);
+            free.push(i);
+        }
+
+        // Fan of new faces from the horizon to the eye, linked to each other around the loop
+        let n = horizon.len();
+        created.clear();
+        for _ in 0..n {
+            created.push(free.pop().unwrap_or_else(|| {
+                faces.push(Face::new([0, 1, 2], [0; 3], points));
+                faces.len() - 1
+            }));
+        }
+        for (k, &(a, b, behind)) in horizon.iter().enumerate() {
+            debug_assert_eq!(b, horizon[(k + 1) % n].0, "horizon is not a closed loop");
+            let neighbors = [behind, created[(k + 1) % n], created[(k + n - 1) % n]];
+            let face = created[k];
+            faces[face] = Face::new([a, b, eye_index], neighbors, points);
+            let edge = faces[behind].edge(b, a).unwrap();
+            faces[behind].neighbors[edge] = face;
+        }
+        for i in orphans {
+            if i != eye_index {
+                assign(&mut faces, &created, i, points, tolerance);
+            }
+        }
+        pending.extend(
+            created
+                .iter()
+                .copied()
+                .filter(|&i| !faces[i].outside.is_empty()),
+        );
+    }
+
+    debug_assert!(
+        faces.iter().filter(|f| f.alive).all(|f| {
+            (0..3).all(|k| {
+                let behind = &faces[f.neighbors[k]];
+                let edge = behind.edge(f.vertices[(k + 1) % 3], f.vertices[k]).unwrap();
+                !f.above(points[behind.vertices[(edge + 2) % 3]], points)
+            })
+        }),
+        "hull has a concave edge"
+    );
+    Some(
+        faces
+            .into_iter()
+            .filter(|f| f.alive)
+            .map(|f| f.vertices)
+            .collect(),
+    )
+}
+
+/// Add a point to the outside set of the first of the candidate faces that it lies in front
+/// of, unless it is within the tolerance of one of them. The distance to the triangle rather
+/// than to its plane is used, since the plane of a thin face is poorly defined
+fn assign<S: Float>(
+    faces: &mut [Face<S>],
+    candidates: &[usize],
+    i: usize,
+    points: &[Vec3<S>],
+    tolerance: S,
+) {
+    let p = points[i];
+    let mut outside = None;
+    for &f in candidates {
+        if !faces[f].above(p, points) {
+            continue;
+        }
+        if faces[f].distance(p) <= tolerance {
+            let [a, b, c] = faces[f].vertices.map(|v| points[v]);
+            if Triangle3::new(a, b, c).squared_distance(p) <= tolerance * tolerance {
+                return;
+            }
+        }
+        outside.get_or_insert(f);
+    }
+    if let Some(f) = outside {
+        faces[f].outside.push(i);
+    }
+}
+
+/// Whether the point lies within the tolerance of the segment between two of its neighbors
+fn on_edge<S: Float>(p: Vec3<S>, neighbors: &[usize], points: &[Vec3<S>], tolerance: S) -> bool {
+    neighbors.iter().enumerate().any(|(k, &a)| {
+        neighbors[k + 1..].iter().any(|&b| {
+            let (a, b) = (points[a], points[b]);
+            let ab = b - a;
+            let t = (p - a).dot(ab);
+            t > S::ZERO
+                && t < ab.magnitude2()
+                && (p - a).cross(ab).magnitude() <= tolerance * ab.magnitude()
+        })
+    })
+}
+
+/// Tetrahedron spanned by extreme points of the set, or `None` if all points are within the
+/// tolerance of a plane
+fn initial_simplex<S: Float>(points: &[Vec3<S>], tolerance: S) -> Option<Vec<Face<S>>> {
+    let extreme = |key: &dyn Fn(Vec3<S>) -> S| {
+        let key = |&i: &usize| key(points[i]);
+        let order = |a: &usize, b: &usize| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal);
+        let min = (0..points.len()).min_by(order)?;
+        let max = (0..points.len()).max_by(order)?;
+        Some((min, max))
+    };
+    let candidates = [extreme(&|p| p.x)?, extreme(&|p| p.y)?, extreme(&|p| p.z)?];
+    let (a, b) = candidates
+        .into_iter()
+        .max_by(|&(a0, a1), &(b0, b1)| {
+            let da = (points[a1] - points[a0]).magnitude2();
+            let db = (points[b1] - points[b0]).magnitude2();
+            da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+        })
+        .unwrap();
+    let (pa, pb) = (points[a], points[b]);
+    let line = (pb - pa).normalize();
+    let (c, line_distance) = farthest(points, |p| (p - pa).cross(line).magnitude());
+    if line_distance <= tolerance {
+        return None;
+    }
+    let normal = line.cross(points[c] - pa).normalize();
+    let (d, plane_distance) = farthest(points, |p| normal.dot(p - pa).abs());
+    if plane_distance <= tolerance {
+        return None;
+    }
+
+    // Orient the faces so that the remaining vertex is behind each of them
+    let (a, b) = if normal.dot(points[d] - pa) > S::ZERO {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    let vertices = [[a, b, c], [a, d, b], [b, d, c], [c, d, a]];
+    Some(
+        vertices
+            .iter()
+            .map(|&f| {
+                // Each face meets the other three, one across each of its edges
+                let neighbors = [0, 1, 2].map(|k| {
+                    let (from, to) = (f[k], f[(k + 1) % 3]);
+                    vertices
+                        .iter()
+                        .position(|g| (0..3).any(|m| g[m] == to && g[(m + 1) % 3] == from))
+                        .unwrap()
+                });
+                Face::new(f, neighbors, points)
+            })
+            .collect(),
+    )
+}
+
+/// Index of the point with the largest distance, and that distance
+fn farthest<S: Float>(points: &[Vec3<S>], distance: impl Fn(Vec3<S>) -> S) -> (usize, S) {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (i, distance(p)))
+        .fold(
+            (0, -S::ONE),
+            |best, next| if next.1 > best.1 { next } else { best },
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConvexHull3, InnerSpace, Polygon2, Vec2, Vec3};
+
+    #[test]
+    fn test_hull_2d() {
+        let mut points = vec![];
+        for x in 0..5 {
+            for y in 0..4 {
+                points.push(Vec2::new(x as f64, y as f64));
+            }
+        }
+        points.push(Vec2::new(2.0, 2.0));
+        points.push(Vec2::new(2.0, -1.0));
+        let hull = Polygon2::convex_hull(&points);
+        assert_eq!(
+            hull.vertices,
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, -1.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(4.0, 3.0),
+                Vec2::new(0.0, 3.0),
+            ]
+        );
+        assert!(hull.is_counterclockwise());
+        assert!(hull.is_convex());
+
+        let collinear = [
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 0.0),
+        ];
+        assert_eq!(
+            Polygon2::convex_hull(&collinear).vertices,
+            vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0)]
+        );
+        assert!(Polygon2::<f64>::convex_hull(&[]).vertices.is_empty());
+    }
+
+    /// Corners of a cube with points on its faces, edges and inside, and duplicates
+    fn cube_points() -> Vec<Vec3<f64>> {
+        let mut points = vec![];
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    points.push(Vec3::new(x as f64 - 1.0, y as f64 - 1.0, z as f64 - 1.0));
+                }
+            }
+        }
+        points.extend_from_within(..);
+        points
+    }
+
+    #[test]
+    fn test_hull_3d_cube() {
+        let points = cube_points();
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        assert_eq!(hull.vertices.len(), 8);
+        assert_eq!(hull.faces.len(), 12);
+        for (&v, &i) in hull.vertices.iter().zip(&hull.indices) {
+            assert_eq!(v, points[i]);
+            assert_eq!(v.x.abs() + v.y.abs() + v.z.abs(), 3.0);
+        }
+        assert_approx_eq!(hull.volume(), 8.0, 1e-12);
+        for f in 0..hull.faces.len() {
+            let n = hull.face_normal(f);
+            assert_approx_eq!(n.x.abs() + n.y.abs() + n.z.abs(), 1.0, 1e-12);
+        }
+        assert!(hull.contains(Vec3::new(0.5, -0.5, 1.0)));
+        assert!(!hull.contains(Vec3::new(0.5, -0.5, 1.1)));
+    }
+
+    #[test]
+    fn test_hull_3d_sphere() {
+        let points = sphere_points(200);
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        assert_closed(&hull);
+        // Closed two-manifold: every edge is shared by exactly two faces
+        let v = hull.vertices.len() as i64;
+        let f = hull.faces.len() as i64;
+        assert_eq!(v - f * 3 / 2 + f, 2);
+        for p in &points {
+            assert!(hull.faces.iter().enumerate().all(|(i, face)| {
+                let a = hull.vertices[face[0]];
+                hull.face_normal(i).dot(*p - a) <= 1e-9
+            }));
+        }
+    }
+
+    /// Points spread over a sphere along a Fibonacci spiral, with a bumpy radius
+    fn sphere_points(n: usize) -> Vec<Vec3<f64>> {
+        (0..n)
+            .map(|i| {
+                let f = i as f64;
+                let z = 1.0 - 2.0 * (f + 0.5) / n as f64;
+                let r = (1.0 - z * z).sqrt();
+                let angle = f * 2.399963229728653;
+                Vec3::new(r * angle.cos(), r * angle.sin(), z) * (1.0 + (f * 0.37).sin() * 0.1)
+            })
+            .collect()
+    }
+
+    /// Every edge is used once in each direction, i.e. the faces form a closed consistently
+    /// oriented surface
+    fn assert_closed(hull: &ConvexHull3<f64>) {
+        let mut edges: Vec<(usize, usize)> = hull
+            .faces
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect();
+        edges.sort_unstable();
+        let len = edges.len();
+        edges.dedup();
+        assert_eq!(edges.len(), len);
+        for &(a, b) in &edges {
+            assert!(edges.binary_search(&(b, a)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hull_3d_many_points() {
+        // Every point is a hull vertex, which is the worst case for the face bookkeeping
+        let points = sphere_points(20000);
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        assert_closed(&hull);
+        let v = hull.vertices.len() as i64;
+        let f = hull.faces.len() as i64;
+        assert_eq!(v - f * 3 / 2 + f, 2);
+        assert!(points.iter().step_by(97).all(|p| {
+            hull.faces.iter().enumerate().all(|(i, face)| {
+                let a = hull.vertices[face[0]];
+                hull.face_normal(i).dot(*p - a) <= 1e-9
+            })
+        }));
+    }
+
+    #[test]
+    fn test_hull_3d_cylinder() {
+        // Points on the side lines between the two rims lie on hull edges and are dropped
+        let mut points = vec![];
+        for ring in 0..8 {
+            for i in 0..64 {
+                let angle = i as f64 * std::f64::consts::TAU / 64.0;
+                points.push(Vec3::new(angle.cos(), angle.sin(), ring as f64 / 7.0));
+            }
+        }
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        assert_eq!(hull.vertices.len(), 128);
+        assert!(hull.vertices.iter().all(|v| v.z == 0.0 || v.z == 1.0));
+        assert_closed(&hull);
+        assert_approx_eq!(
+            hull.volume(),
+            32.0 * (std::f64::consts::TAU / 64.0).sin(),
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_hull_3d_jittered_grid() {
+        // Nearly coplanar points make thin faces whose normals are too inaccurate to decide
+        // visibility with, which used to fold the hull
+        let mut state = 0x9e3779b97f4a7c15u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for _ in 0..100 {
+            let mut points = vec![];
+            for x in 0..5 {
+                for y in 0..5 {
+                    for z in 0..5 {
+                        let [x, y, z] = [x, y, z].map(|c| c as f64 + random() * 1e-14);
+                        points.push(Vec3::new(x, y, z));
+                    }
+                }
+            }
+            let hull = ConvexHull3::from_points(&points).unwrap();
+            assert_closed(&hull);
+            assert!(hull.contains(Vec3::new(2.0, 2.0, 2.0)));
+            for (i, face) in hull.faces.iter().enumerate() {
+                let (a, normal) = (hull.vertices[face[0]], hull.face_normal(i));
+                assert!(hull.vertices.iter().all(|v| normal.dot(*v - a) <= 1e-12));
+                assert!(points.iter().all(|p| normal.dot(*p - a) <= 1e-12));
+            }
+            assert_approx_eq!(hull.volume(), 64.0, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hull_3d_degenerate() {
+        let flat = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+        assert_eq!(ConvexHull3::from_points(&flat), None);
+        assert_eq!(ConvexHull3::from_points(&flat[..2]), None);
+        assert_eq!(ConvexHull3::<f64>::from_points(&[]), None);
+        let tetrahedron = [flat[0], flat[1], flat[2], Vec3::new(0.0, 0.0, 1.0)];
+        let hull = ConvexHull3::from_points(&tetrahedron).unwrap();
+        assert_eq!(hull.faces.len(), 4);
+        assert_approx_eq!(hull.volume(), 1.0 / 6.0, 1e-12);
+    }
+}
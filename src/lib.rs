@@ -1,21 +1,55 @@
 #[macro_use]
 mod macros;
 
+mod aabb;
 mod angle;
+mod camera;
+mod capsule;
 mod crevice; // TODO Make this a feature, consider using encase
+mod culling;
+mod cylinder;
+mod error;
 mod euler;
+mod geometry2d;
+mod gjk;
+mod hull;
 mod matrix;
 mod num;
+mod obb;
+mod plane;
+mod predicates;
 mod projection;
 mod quaternion;
+mod ray;
+mod segment;
+mod sphere;
 mod structure;
+mod triangle;
 mod vector;
 
-pub use angle::{Angle, Deg, Rad};
-pub use euler::Euler;
+pub use aabb::{Aabb2, Aabb3};
+pub use angle::{Angle, AngleRange, BAngle16, BAngle32, Deg, Grad, Rad, Turn};
+pub use camera::{ArcballCamera, Camera2d, FlyCamera, OrbitCamera};
+pub use capsule::Capsule;
+pub use culling::{FrustumPlanes, Intersection};
+pub use cylinder::Cylinder;
+pub use error::Error;
+pub use euler::{Euler, EulerAxes, EulerOrder};
+pub use geometry2d::{Circle, Polygon2, Rect};
+pub use gjk::{Contact, SupportMap, Transformed};
+pub use hull::ConvexHull3;
 pub use matrix::{Mat2, Mat3, Mat4, Matrix, SquareMatrix};
 pub use num::{Float, Num, One, Signed, Zero};
-pub use projection::{Ortho, Perspective};
+pub use obb::{Obb2, Obb3};
+pub use plane::{Plane, Side};
+pub use projection::{
+    ClipSpace, DepthRange, Detected, Eye, Frustum, Handedness, Ortho, Perspective,
+    PerspectiveInfinite, Projection, ProjectionError, ReverseZ,
+};
 pub use quaternion::Quat;
+pub use ray::{Ray2, Ray3, RayHit2, RayHit3, TriangleHit};
+pub use segment::{Segment2, Segment3, SegmentIntersection};
+pub use sphere::Sphere;
 pub use structure::{ElementWise, InnerSpace, MetricSpace, VectorSpace};
+pub use triangle::{Triangle2, Triangle3};
 pub use vector::{Vec1, Vec2, Vec3, Vec4, Vector};
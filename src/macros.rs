@@ -162,3 +162,60 @@ macro_rules! impl_tuple_conversions {
         }
     }
 }
+
+#[cfg(test)]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {
+        assert_approx_eq!($a, $b, 1e-5)
+    };
+    ($a:expr, $b:expr, $eps:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            crate::macros::ApproxEq::approx_eq(a, b, $eps),
+            "assertion failed: `{:?}` is not approximately equal to `{:?}`",
+            a,
+            b
+        );
+    }};
+}
+
+#[cfg(test)]
+pub(crate) trait ApproxEq {
+    fn approx_eq(&self, other: &Self, eps: f64) -> bool;
+}
+
+#[cfg(test)]
+mod approx {
+    use super::ApproxEq;
+    use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+    impl ApproxEq for f32 {
+        fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+            ((self - other).abs() as f64) <= eps
+        }
+    }
+
+    impl ApproxEq for f64 {
+        fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+            (self - other).abs() <= eps
+        }
+    }
+
+    macro_rules! impl_approx_eq {
+        ($T:ident { $($field:ident),+ }) => {
+            impl<S: ApproxEq> ApproxEq for $T<S> {
+                fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+                    $(self.$field.approx_eq(&other.$field, eps))&&+
+                }
+            }
+        };
+    }
+
+    impl_approx_eq!(Vec2 { x, y });
+    impl_approx_eq!(Vec3 { x, y, z });
+    impl_approx_eq!(Vec4 { x, y, z, w });
+    impl_approx_eq!(Mat2 { x, y });
+    impl_approx_eq!(Mat3 { x, y, z });
+    impl_approx_eq!(Mat4 { x, y, z, w });
+    impl_approx_eq!(Quat { s, v });
+}
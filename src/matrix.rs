@@ -2,7 +2,10 @@ use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
-use crate::{Angle, Float, InnerSpace, Quat, Rad, Vec2, Vec3, Vec4, VectorSpace, Zero};
+use crate::{
+    Angle, Error, Float, InnerSpace, One, Plane, Quat, Rad, Signed, Vec2, Vec3, Vec4, Vector,
+    VectorSpace, Zero,
+};
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -52,7 +55,7 @@ where
     Self: Mul<<Self as SquareMatrix>::ColumnRow, Output = <Self as SquareMatrix>::ColumnRow>,
     Self: Mul<Self, Output = Self>,
 {
-    type ColumnRow: VectorSpace<Scalar = Self::Scalar>;
+    type ColumnRow: InnerSpace + Vector<Element = Self::Scalar> + VectorSpace<Scalar = Self::Scalar>;
 
     const IDENTITY: Self;
 
@@ -67,6 +70,26 @@ where
     fn trace(&self) -> Self::Scalar;
 
     fn inverse(&self) -> Option<Self>;
+
+    /// Like `inverse`, but tells apart singular matrices and matrices with non-finite elements
+    ///
+    /// A matrix counts as singular when its determinant is within rounding error of zero,
+    /// relative to the product of its column lengths (the largest determinant those columns allow)
+    fn try_inverse(&self) -> Result<Self, Error> {
+        let det = self.determinant();
+        if !det.is_finite() {
+            return Err(Error::NonFinite);
+        }
+        let mut bound = Self::Scalar::ONE;
+        for c in 0..Self::ColumnRow::length() {
+            bound *= self.column(c).magnitude();
+        }
+        if !det.recip().is_finite() || det.abs() <= bound * Self::Scalar::EPSILON {
+            Err(Error::Singular)
+        } else {
+            self.inverse().ok_or(Error::Singular)
+        }
+    }
 }
 
 impl<S: Float> Zero for Mat2<S> {
@@ -225,7 +248,7 @@ impl<S: Float> SquareMatrix for Mat3<S> {
     fn determinant(&self) -> Self::Scalar {
         let da = (self.y.y * self.z.z) - (self.y.z * self.z.y);
         let db = (self.x.y * self.z.z) - (self.x.z * self.z.y);
-        let dc = (self.x.y * self.y.z) - (self.x.z * self.y.x);
+        let dc = (self.x.y * self.y.z) - (self.x.z * self.y.y);
         self.x.x * da - self.y.x * db + self.z.x * dc
     }
 
@@ -354,9 +377,9 @@ impl<S: Float> Mat3<S> {
         let c = angle.cos();
 
         Mat3::new(
-            c, S::ZERO, s,
+            c, S::ZERO, -s,
             S::ZERO, S::ONE, S::ZERO,
-            -s, S::ZERO, c,
+            s, S::ZERO, c,
         )
     }
 
@@ -373,6 +396,89 @@ impl<S: Float> Mat3<S> {
             S::ZERO, S::ZERO, S::ONE,
         )
     }
+
+    /// Orthonormal right, up and forward vectors of a right-handed view looking in the
+    /// direction `dir`. When `up` is zero or parallel to `dir` another up vector is used
+    fn look_basis(dir: Vec3<S>, up: Vec3<S>) -> (Vec3<S>, Vec3<S>, Vec3<S>) {
+        let f = dir.normalize();
+        let s = f.cross(up);
+        let s = if s.magnitude2() > S::EPSILON * up.magnitude2() {
+            s.normalize()
+        } else {
+            f.any_orthonormal()
+        };
+        (s, s.cross(f), f)
+    }
+
+    /// Right-handed view rotation looking in the direction `dir`, with the negative z axis
+    /// pointing along `dir`. When `up` is parallel to `dir` another up vector is used
+    #[rustfmt::skip]
+    pub fn look_to(dir: Vec3<S>, up: Vec3<S>) -> Mat3<S> {
+        let (s, u, f) = Mat3::look_basis(dir, up);
+        Mat3::new(
+            s.x, u.x, -f.x,
+            s.y, u.y, -f.y,
+            s.z, u.z, -f.z,
+        )
+    }
+
+    /// Left-handed view rotation looking in the direction `dir`, with the positive z axis
+    /// pointing along `dir`. When `up` is parallel to `dir` another up vector is used
+    #[rustfmt::skip]
+    pub fn look_to_lh(dir: Vec3<S>, up: Vec3<S>) -> Mat3<S> {
+        let (s, u, f) = Mat3::look_basis(dir, up);
+        Mat3::new(
+            -s.x, u.x, f.x,
+            -s.y, u.y, f.y,
+            -s.z, u.z, f.z,
+        )
+    }
+
+    /// Whether the columns are unit vectors orthogonal to each other, i.e. the matrix is a
+    /// rotation or a reflection. `tolerance` bounds the Frobenius norm of `transpose * self - I`
+    pub fn is_orthonormal(&self, tolerance: S) -> bool {
+        let error = self.transpose() * *self - Mat3::IDENTITY;
+        error.x.magnitude2() + error.y.magnitude2() + error.z.magnitude2() <= tolerance * tolerance
+    }
+
+    /// Transform a 2D point, dividing the result by the homogeneous coordinate
+    #[inline]
+    pub fn transform_point2(&self, point: Vec2<S>) -> Vec2<S> {
+        let p = self.x * point.x + self.y * point.y + self.z;
+        p.truncate() / p.z
+    }
+
+    /// Transform a 2D direction vector, ignoring the translation part of the matrix
+    #[inline]
+    pub fn transform_vector2(&self, vector: Vec2<S>) -> Vec2<S> {
+        (self.x * vector.x + self.y * vector.y).truncate()
+    }
+
+    /// Transform a 2D point by a projective matrix. Returns `None` if the point is projected
+    /// from behind the center of projection, i.e. the homogeneous coordinate is not positive
+    #[inline]
+    pub fn project_point2(&self, point: Vec2<S>) -> Option<Vec2<S>> {
+        let p = self.x * point.x + self.y * point.y + self.z;
+        if p.z > S::ZERO {
+            Some(p.truncate() / p.z)
+        } else {
+            None
+        }
+    }
+
+    /// Transform a slice of 2D points in place, see `transform_point2`
+    pub fn transform_points2(&self, points: &mut [Vec2<S>]) {
+        for point in points {
+            *point = self.transform_point2(*point);
+        }
+    }
+
+    /// Transform a slice of 2D direction vectors in place, see `transform_vector2`
+    pub fn transform_vectors2(&self, vectors: &mut [Vec2<S>]) {
+        for vector in vectors {
+            *vector = self.transform_vector2(*vector);
+        }
+    }
 }
 
 impl<S: Float> Matrix for Mat3<S> {
@@ -638,9 +744,9 @@ impl<S: Float> Mat4<S> {
         let c = angle.cos();
 
         Mat4::new(
-            c, S::ZERO, s,S::ZERO,
+            c, S::ZERO, -s, S::ZERO,
             S::ZERO, S::ONE, S::ZERO, S::ZERO,
-            -s, S::ZERO, c, S::ZERO,
+            s, S::ZERO, c, S::ZERO,
             S::ZERO, S::ZERO, S::ZERO, S::ONE,
         )
     }
@@ -660,22 +766,65 @@ impl<S: Float> Mat4<S> {
         )
     }
 
+    /// Mirror transformation across a plane, which must have a unit normal. Multiplying a view
+    /// matrix by it from the right gives the view of a planar mirror camera. The reflection
+    /// flips the handedness, so the winding order of triangles has to be reversed as well
+    #[inline]
     #[rustfmt::skip]
+    pub fn from_reflection(plane: &Plane<S>) -> Mat4<S> {
+        let Plane { normal: n, d } = *plane;
+        let two = S::ONE + S::ONE;
+
+        Mat4::new(
+            S::ONE - two * n.x * n.x, -two * n.y * n.x, -two * n.z * n.x, S::ZERO,
+            -two * n.x * n.y, S::ONE - two * n.y * n.y, -two * n.z * n.y, S::ZERO,
+            -two * n.x * n.z, -two * n.y * n.z, S::ONE - two * n.z * n.z, S::ZERO,
+            -two * d * n.x, -two * d * n.y, -two * d * n.z, S::ONE,
+        )
+    }
+
+    /// View matrix with the given rotation for a camera at `eye`
+    fn from_view_rotation(rotation: Mat3<S>, eye: Vec3<S>) -> Mat4<S> {
+        let mut m = Mat4::from(rotation);
+        m.w = (rotation * -eye).extend(S::ONE);
+        m
+    }
+
+    /// Right-handed view matrix for a camera at `eye` looking at `center`, same as `look_at_rh`
+    #[inline]
     pub fn look_at(eye: Vec3<S>, center: Vec3<S>, up: Vec3<S>) -> Mat4<S> {
-        let f = (center - eye).normalize();
-        let s = f.cross(up.normalize());
-        let u = s.normalize().cross(f);
-        let m = Mat4::new(
-            s.x, u.x, -f.x, S::ZERO,
-            s.y, u.y, -f.y, S::ZERO,
-            s.z, u.z, -f.z, S::ZERO,
-            S::ZERO, S::ZERO, S::ZERO, S::ONE,
-        );
-        m * Mat4::from_translation(-eye)
+        Mat4::look_at_rh(eye, center, up)
     }
 
-    /// Extract scale, rotation and translation from affine transformation matrix
-    pub fn to_scale_quaternion_translation(&self) -> (Vec3<S>, Quat<S>, Vec3<S>) {
+    /// Right-handed view matrix for a camera at `eye` looking at `center`. When `up` is
+    /// parallel to the view direction another up vector is used
+    #[inline]
+    pub fn look_at_rh(eye: Vec3<S>, center: Vec3<S>, up: Vec3<S>) -> Mat4<S> {
+        Mat4::look_to_rh(eye, center - eye, up)
+    }
+
+    /// Left-handed view matrix for a camera at `eye` looking at `center`. When `up` is
+    /// parallel to the view direction another up vector is used
+    #[inline]
+    pub fn look_at_lh(eye: Vec3<S>, center: Vec3<S>, up: Vec3<S>) -> Mat4<S> {
+        Mat4::look_to_lh(eye, center - eye, up)
+    }
+
+    /// Right-handed view matrix for a camera at `eye` looking in the direction `dir`
+    #[inline]
+    pub fn look_to_rh(eye: Vec3<S>, dir: Vec3<S>, up: Vec3<S>) -> Mat4<S> {
+        Mat4::from_view_rotation(Mat3::look_to(dir, up), eye)
+    }
+
+    /// Left-handed view matrix for a camera at `eye` looking in the direction `dir`
+    #[inline]
+    pub fn look_to_lh(eye: Vec3<S>, dir: Vec3<S>, up: Vec3<S>) -> Mat4<S> {
+        Mat4::from_view_rotation(Mat3::look_to_lh(dir, up), eye)
+    }
+
+    /// Scale and the remaining rotation part of an affine transformation matrix. Rotation
+    /// axes with a zero scale are completed to a right-handed orthonormal basis
+    pub(crate) fn scale_rotation(&self) -> (Vec3<S>, Mat3<S>) {
         let det = self.determinant();
 
         let scale: Vec3<S> = Vec3::new(
@@ -684,18 +833,62 @@ impl<S: Float> Mat4<S> {
             self.z.magnitude(),
         );
 
-        let inv_scale = scale.recip();
-
-        let rotation = Mat3::from_cols(
-            (self.x * inv_scale.x).truncate(),
-            (self.y * inv_scale.y).truncate(),
-            (self.z * inv_scale.z).truncate(),
-        )
-        .into();
-
-        let translation = self.w.truncate();
-
-        (scale, rotation, translation)
+        let axis =
+            |column: Vec4<S>, scale: S| (scale != S::ZERO).then(|| (column / scale).truncate());
+
+        let rotation = match (
+            axis(self.x, scale.x),
+            axis(self.y, scale.y),
+            axis(self.z, scale.z),
+        ) {
+            (Some(x), Some(y), Some(z)) => Mat3::from_cols(x, y, z),
+            (Some(x), Some(y), None) => Mat3::from_cols(x, y, x.cross(y).normalize()),
+            (Some(x), None, Some(z)) => Mat3::from_cols(x, z.cross(x).normalize(), z),
+            (None, Some(y), Some(z)) => Mat3::from_cols(y.cross(z).normalize(), y, z),
+            (Some(x), None, None) => {
+                let y = x.any_orthonormal();
+                Mat3::from_cols(x, y, x.cross(y))
+            }
+            (None, Some(y), None) => {
+                let z = y.any_orthonormal();
+                Mat3::from_cols(y.cross(z), y, z)
+            }
+            (None, None, Some(z)) => {
+                let x = z.any_orthonormal();
+                Mat3::from_cols(x, z.cross(x), z)
+            }
+            (None, None, None) => Mat3::IDENTITY,
+        };
+
+        (scale, rotation)
+    }
+
+    /// Extract scale, rotation and translation from affine transformation matrix. The rotation
+    /// is completed to an orthonormal basis along axes with a zero scale
+    pub fn to_scale_quaternion_translation(&self) -> (Vec3<S>, Quat<S>, Vec3<S>) {
+        let (scale, rotation) = self.scale_rotation();
+        (scale, rotation.into(), self.w.truncate())
+    }
+
+    /// Like `to_scale_quaternion_translation`, but checks that the matrix is a finite affine
+    /// transformation without shear or zero scale, within `tolerance`
+    #[allow(clippy::type_complexity)]
+    pub fn try_to_scale_quaternion_translation(
+        &self,
+        tolerance: S,
+    ) -> Result<(Vec3<S>, Quat<S>, Vec3<S>), Error> {
+        if !self.is_finite() {
+            return Err(Error::NonFinite);
+        }
+        if (self.row(3) - Vec4::W).magnitude() > tolerance {
+            return Err(Error::NotAffine);
+        }
+        let (scale, rotation) = self.scale_rotation();
+        if scale.x.abs().min(scale.y.abs()).min(scale.z.abs()) <= tolerance {
+            return Err(Error::Singular);
+        }
+        let rotation = Quat::try_from_rotation(rotation, tolerance)?;
+        Ok((scale, rotation, self.w.truncate()))
     }
 
     /// Create affine transformation matrix from scale, rotation and translation.
@@ -713,6 +906,112 @@ impl<S: Float> Mat4<S> {
             translation.extend(S::ONE),
         )
     }
+
+    /// Invert an affine transformation matrix, i.e. a matrix whose last row is `[0, 0, 0, 1]`.
+    /// Considerably cheaper than the general `inverse`
+    pub fn inverse_affine(&self) -> Option<Mat4<S>> {
+        let m = Mat3::from(*self);
+        let cofactor = Mat3::from_cols(m.y.cross(m.z), m.z.cross(m.x), m.x.cross(m.y));
+        let det = m.x.dot(cofactor.x);
+        if det == S::ZERO {
+            return None;
+        }
+        let inv_det = det.recip();
+        let inv = cofactor.transpose() * inv_det;
+        let t = self.w.truncate();
+        let translation =
+            -Vec3::new(cofactor.x.dot(t), cofactor.y.dot(t), cofactor.z.dot(t)) * inv_det;
+        Some(Mat4::from_cols(
+            inv.x.extend(S::ZERO),
+            inv.y.extend(S::ZERO),
+            inv.z.extend(S::ZERO),
+            translation.extend(S::ONE),
+        ))
+    }
+
+    /// Invert a rigid transformation matrix consisting only of rotation and translation
+    pub fn inverse_rigid(&self) -> Mat4<S> {
+        let inv = Mat3::from(*self).transpose();
+        let translation = -(inv * self.w.truncate());
+        Mat4::from_cols(
+            inv.x.extend(S::ZERO),
+            inv.y.extend(S::ZERO),
+            inv.z.extend(S::ZERO),
+            translation.extend(S::ONE),
+        )
+    }
+
+    /// Inverse-transpose of the upper 3x3 part of the matrix, used to transform normals.
+    /// If the upper 3x3 part is singular, its cofactor matrix is returned instead, which still
+    /// maps normals to the correct direction up to scale
+    pub fn normal_matrix(&self) -> Mat3<S> {
+        let m = Mat3::from(*self);
+        let cofactor = Mat3::from_cols(m.y.cross(m.z), m.z.cross(m.x), m.x.cross(m.y));
+        let det = m.x.dot(cofactor.x);
+        if det == S::ZERO {
+            cofactor
+        } else {
+            cofactor / det
+        }
+    }
+
+    /// Transform a 3D point, dividing the result by the homogeneous coordinate
+    #[inline]
+    pub fn transform_point3(&self, point: Vec3<S>) -> Vec3<S> {
+        Vec3::from_homogeneous(self.x * point.x + self.y * point.y + self.z * point.z + self.w)
+    }
+
+    /// Transform a 3D direction vector, ignoring the translation part of the matrix
+    #[inline]
+    pub fn transform_vector3(&self, vector: Vec3<S>) -> Vec3<S> {
+        (self.x * vector.x + self.y * vector.y + self.z * vector.z).truncate()
+    }
+
+    /// Transform a 3D point by a projection matrix. Returns `None` if the point lies behind
+    /// the center of projection, i.e. the clip space w coordinate is not positive
+    #[inline]
+    pub fn project_point3(&self, point: Vec3<S>) -> Option<Vec3<S>> {
+        let p = self.x * point.x + self.y * point.y + self.z * point.z + self.w;
+        if p.w > S::ZERO {
+            Some(Vec3::from_homogeneous(p))
+        } else {
+            None
+        }
+    }
+
+    /// Transform a slice of 3D points in place, see `transform_point3`
+    pub fn transform_points3(&self, points: &mut [Vec3<S>]) {
+        for point in points {
+            *point = self.transform_point3(*point);
+        }
+    }
+
+    /// Transform a slice of 3D direction vectors in place, see `transform_vector3`
+    pub fn transform_vectors3(&self, vectors: &mut [Vec3<S>]) {
+        for vector in vectors {
+            *vector = self.transform_vector3(*vector);
+        }
+    }
+}
+
+/// Upper-left 3x3 part of the matrix
+impl<S: Float> From<Mat4<S>> for Mat3<S> {
+    #[inline]
+    fn from(mat: Mat4<S>) -> Mat3<S> {
+        Mat3::from_cols(mat.x.truncate(), mat.y.truncate(), mat.z.truncate())
+    }
+}
+
+impl<S: Float> From<Mat3<S>> for Mat4<S> {
+    #[inline]
+    fn from(mat: Mat3<S>) -> Mat4<S> {
+        Mat4::from_cols(
+            mat.x.extend(S::ZERO),
+            mat.y.extend(S::ZERO),
+            mat.z.extend(S::ZERO),
+            Vec4::W,
+        )
+    }
 }
 
 impl<S: Float> Matrix for Mat4<S> {
@@ -766,6 +1065,13 @@ impl_operator!(<S: Float> Mul<Mat4<S>> for Mat4<S> {
 
 macro_rules! impl_matrix {
     ($MatN:ident, $VecN:ident { $($field:ident : $row_index:expr),+ }) => {
+        impl<S: Float> $MatN<S> {
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
+        }
+
         impl_operator!(<S: Float> Neg for $MatN<S> {
             fn neg(matrix) -> $MatN<S> { $MatN { $($field: -matrix.$field),+ } }
         });
@@ -916,3 +1222,258 @@ impl<S: Float> VectorSpace for Mat3<S> {
 impl<S: Float> VectorSpace for Mat4<S> {
     type Scalar = S;
 }
+
+#[cfg(test)]
+mod tests {
+    mod mat3 {
+        use crate::{Mat3, Matrix, SquareMatrix, Vec2};
+
+        #[test]
+        fn test_inverse() {
+            let m = Mat3::new(2.0, 1.0, 0.5, 0.0, 3.0, 1.0, 1.0, 0.0, 4.0);
+            assert_approx_eq!(m * m.inverse().unwrap(), Mat3::<f64>::IDENTITY, 1e-12);
+        }
+
+        #[test]
+        fn test_determinant() {
+            // Columns (2, 1, 0.5), (0, 3, 1) and (1, 0, 4), so the determinant is the triple
+            // product x . (y x z) = (2, 1, 0.5) . (12, 1, -3)
+            let m = Mat3::new(2.0, 1.0, 0.5, 0.0, 3.0, 1.0, 1.0, 0.0, 4.0);
+            assert_eq!(m.determinant(), 23.5);
+            assert_eq!(m.transpose().determinant(), 23.5);
+        }
+
+        #[test]
+        fn test_transform_point2() {
+            let m = Mat3::from_translation(Vec2::new(1.0, 2.0))
+                * Mat3::from_nonuniform_scale(2.0, 2.0, 1.0);
+            assert_approx_eq!(m.transform_point2(Vec2::new(1.0, 1.0)), Vec2::new(3.0, 4.0));
+            assert_approx_eq!(
+                m.transform_vector2(Vec2::new(1.0, 1.0)),
+                Vec2::new(2.0, 2.0)
+            );
+            let mut points = [Vec2::new(1.0, 1.0), Vec2::new(0.0, -1.0)];
+            m.transform_points2(&mut points);
+            assert_approx_eq!(points[0], Vec2::new(3.0, 4.0));
+            assert_approx_eq!(points[1], Vec2::new(1.0, 0.0));
+        }
+
+        #[test]
+        fn test_project_point2() {
+            let m = Mat3::new(1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+            assert_approx_eq!(
+                m.project_point2(Vec2::new(4.0, 2.0)).unwrap(),
+                Vec2::new(1.0, 0.5)
+            );
+            assert_eq!(m.project_point2(Vec2::new(-4.0, 2.0)), None);
+        }
+    }
+
+    mod mat4 {
+        use crate::{
+            Deg, Error, Euler, InnerSpace, Mat3, Mat4, Matrix, Perspective, Quat, SquareMatrix,
+            Vec3,
+        };
+
+        fn affine() -> Mat4<f64> {
+            Mat4::from_translation(Vec3::new(1.0, -2.0, 3.0))
+                * Mat4::from_rotation(Vec3::new(1.0, 2.0, -1.0).normalize(), Deg(37.0))
+                * Mat4::from_nonuniform_scale(2.0, 0.5, 3.0)
+        }
+
+        fn rigid() -> Mat4<f64> {
+            Mat4::from_translation(Vec3::new(-4.0, 0.5, 2.0))
+                * Mat4::from(Quat::from(Euler::new(Deg(10.0), Deg(-70.0), Deg(25.0))))
+        }
+
+        #[test]
+        fn test_inverse() {
+            let m = affine();
+            assert_approx_eq!(m * m.inverse().unwrap(), Mat4::IDENTITY, 1e-12);
+        }
+
+        #[test]
+        fn test_inverse_affine() {
+            let m = affine();
+            assert_approx_eq!(m.inverse_affine().unwrap(), m.inverse().unwrap(), 1e-12);
+            assert_eq!(Mat4::from_scale(0.0).inverse_affine(), None);
+        }
+
+        #[test]
+        fn test_inverse_rigid() {
+            let m = rigid();
+            assert_approx_eq!(m.inverse_rigid(), m.inverse().unwrap(), 1e-12);
+        }
+
+        #[test]
+        fn test_normal_matrix() {
+            let m = affine();
+            let expected = Mat3::from(m.inverse().unwrap()).transpose();
+            assert_approx_eq!(m.normal_matrix(), expected, 1e-12);
+            assert_approx_eq!(rigid().normal_matrix(), Mat3::from(rigid()), 1e-12);
+        }
+
+        #[test]
+        fn test_transform_point3() {
+            let m = affine();
+            let p = Vec3::new(0.5, -1.0, 2.0);
+            let expected = Vec3::from_homogeneous(m * p.extend(1.0));
+            assert_approx_eq!(m.transform_point3(p), expected, 1e-12);
+            assert_approx_eq!(
+                m.transform_vector3(p),
+                (m * p.extend(0.0)).truncate(),
+                1e-12
+            );
+
+            let mut points = [p, -p];
+            m.transform_points3(&mut points);
+            assert_approx_eq!(points[0], expected, 1e-12);
+            let mut vectors = [p];
+            m.transform_vectors3(&mut vectors);
+            assert_approx_eq!(vectors[0], m.transform_vector3(p), 1e-12);
+        }
+
+        #[test]
+        fn test_project_point3() {
+            let proj = Mat4::from(Perspective {
+                fovy: Deg(90.0).into(),
+                aspect: 1.0,
+                near: 1.0,
+                far: 10.0,
+            });
+            let p = proj.project_point3(Vec3::new(1.0, 1.0, -2.0)).unwrap();
+            assert_approx_eq!(p, proj.transform_point3(Vec3::new(1.0, 1.0, -2.0)), 1e-12);
+            assert_approx_eq!(p.x, 0.5, 1e-12);
+            assert_eq!(proj.project_point3(Vec3::new(1.0, 1.0, 2.0)), None);
+        }
+
+        #[test]
+        fn test_try_inverse() {
+            let m = affine();
+            assert_approx_eq!(m.try_inverse().unwrap(), m.inverse().unwrap(), 1e-12);
+            let singular = Mat4::from_nonuniform_scale(1.0, 0.0, 2.0);
+            assert_eq!(singular.try_inverse(), Err(Error::Singular));
+            let mut nan = m;
+            nan.y.z = f64::NAN;
+            assert_eq!(nan.try_inverse(), Err(Error::NonFinite));
+            assert_eq!(
+                Mat3::from_scale(f64::INFINITY).try_inverse(),
+                Err(Error::NonFinite)
+            );
+
+            // Nearly singular: the determinant is rounding noise
+            let nearly = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0 + 1e-15);
+            assert_eq!(nearly.try_inverse(), Err(Error::Singular));
+            // The tolerance follows the scale of the matrix
+            let tiny = m * Mat4::from_scale(1e-5);
+            assert_approx_eq!(tiny.try_inverse().unwrap() * tiny, Mat4::IDENTITY, 1e-9);
+        }
+
+        #[test]
+        fn test_try_to_scale_quaternion_translation() {
+            let rotation = Quat::from(Euler::new(Deg(10.0f64), Deg(-70.0), Deg(25.0)));
+            let m = Mat4::from_scale_quaternion_translation(
+                Vec3::new(2.0, 0.5, 3.0),
+                rotation,
+                Vec3::new(1.0, -2.0, 3.0),
+            );
+            let (scale, q, translation) = m.try_to_scale_quaternion_translation(1e-9).unwrap();
+            assert_approx_eq!(scale, Vec3::new(2.0, 0.5, 3.0), 1e-12);
+            assert_approx_eq!(q * q.s.signum(), rotation * rotation.s.signum(), 1e-12);
+            assert_approx_eq!(translation, Vec3::new(1.0, -2.0, 3.0), 1e-12);
+
+            let zero_scale = m * Mat4::from_nonuniform_scale(1.0, 0.0, 1.0);
+            assert_eq!(
+                zero_scale.try_to_scale_quaternion_translation(1e-9),
+                Err(Error::Singular)
+            );
+            // The unchecked decomposition completes the missing axes instead of dividing by zero
+            for scale in [
+                Vec3::new(2.0, 0.0, 3.0),
+                Vec3::new(0.0, 0.0, 3.0),
+                Vec3::new(0.0, 0.5, 0.0),
+                Vec3::new(0.0, 0.0, 0.0),
+            ] {
+                let m = Mat4::from_scale_quaternion_translation(scale, rotation, translation);
+                let (s, q, t) = m.to_scale_quaternion_translation();
+                assert_approx_eq!(s, scale, 1e-12);
+                assert_approx_eq!(q.magnitude(), 1.0, 1e-12);
+                assert_approx_eq!(Mat4::from_scale_quaternion_translation(s, q, t), m, 1e-12);
+            }
+            let mut shear = m;
+            shear.y += shear.x * 0.5;
+            assert_eq!(
+                shear.try_to_scale_quaternion_translation(1e-9),
+                Err(Error::NotOrthonormal)
+            );
+            let projective = Mat4::from(Perspective {
+                fovy: Deg(90.0).into(),
+                aspect: 1.0,
+                near: 1.0,
+                far: 10.0,
+            });
+            assert_eq!(
+                projective.try_to_scale_quaternion_translation(1e-9),
+                Err(Error::NotAffine)
+            );
+            let mut infinite = m;
+            infinite.w.x = f64::INFINITY;
+            assert_eq!(
+                infinite.try_to_scale_quaternion_translation(1e-9),
+                Err(Error::NonFinite)
+            );
+        }
+
+        #[test]
+        fn test_look_at() {
+            let (eye, center) = (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.0, 1.0));
+            let distance = (center - eye).magnitude();
+            let rh = Mat4::look_at_rh(eye, center, Vec3::Y);
+            assert_approx_eq!(rh, Mat4::look_at(eye, center, Vec3::Y), 1e-12);
+            assert_approx_eq!(rh, Mat4::look_to_rh(eye, center - eye, Vec3::Y), 1e-12);
+            assert_approx_eq!(rh.transform_point3(eye), Vec3::new(0.0, 0.0, 0.0), 1e-12);
+            assert_approx_eq!(
+                rh.transform_point3(center),
+                Vec3::new(0.0, 0.0, -distance),
+                1e-12
+            );
+
+            let lh = Mat4::look_at_lh(eye, center, Vec3::Y);
+            assert_approx_eq!(
+                lh.transform_point3(center),
+                Vec3::new(0.0, 0.0, distance),
+                1e-12
+            );
+            assert_approx_eq!(lh.determinant(), 1.0, 1e-12);
+            // The up vector stays in the upper half of the view
+            assert!(rh.transform_vector3(Vec3::Y).y > 0.0);
+            assert!(lh.transform_vector3(Vec3::Y).y > 0.0);
+            // Right-handed and left-handed views differ by a mirrored x axis
+            let mirror = Mat4::from_nonuniform_scale(-1.0, 1.0, -1.0);
+            assert_approx_eq!(mirror * rh, lh, 1e-12);
+        }
+
+        #[test]
+        fn test_look_to_degenerate_up() {
+            let eye = Vec3::new(0.0, 5.0, 0.0);
+            for up in [Vec3::Y, -Vec3::Y, Vec3::new(0.0, 0.0, 0.0)] {
+                let m = Mat4::look_to_rh(eye, Vec3::new(0.0, -1.0, 0.0), up);
+                assert!(m.transform_vector3(Vec3::<f64>::X).x.is_finite());
+                let rotation = Mat3::from(m);
+                assert_approx_eq!(rotation * rotation.transpose(), Mat3::IDENTITY, 1e-12);
+                assert_approx_eq!(
+                    m.transform_point3(Vec3::new(0.0, 0.0, 0.0)),
+                    Vec3::new(0.0, 0.0, -5.0),
+                    1e-12
+                );
+            }
+        }
+
+        #[test]
+        fn test_normal_matrix_singular() {
+            let m = Mat4::from_nonuniform_scale(2.0, 3.0, 0.0);
+            let n = m.normal_matrix() * Vec3::new(0.0, 0.0, 1.0);
+            assert_approx_eq!(n.normalize(), Vec3::new(0.0, 0.0, 1.0), 1e-12);
+        }
+    }
+}
@@ -162,13 +162,20 @@ impl_signed!(f64);
 
 pub trait Float: Signed {
     const HALF: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
     const RAD_FULL_TURN: Self;
     const RAD_HALF_TURN: Self;
     const DEG_FULL_TURN: Self;
     const DEG_HALF_TURN: Self;
     const DEG_RAD_RATIO: Self;
+    const GRAD_FULL_TURN: Self;
+    const GRAD_HALF_TURN: Self;
 
     fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn is_finite(self) -> bool;
     fn round(self) -> Self;
     fn trunc(self) -> Self;
     fn fract(self) -> Self;
@@ -187,17 +194,33 @@ macro_rules! impl_float {
     ($t:ty, $pi:expr) => {
         impl Float for $t {
             const HALF: Self = 0.5;
+            const EPSILON: Self = <$t>::EPSILON;
+            const INFINITY: Self = <$t>::INFINITY;
             const RAD_FULL_TURN: Self = 2.0 * $pi;
             const RAD_HALF_TURN: Self = $pi;
             const DEG_FULL_TURN: Self = 360.0;
             const DEG_HALF_TURN: Self = 180.0;
             const DEG_RAD_RATIO: Self = 180.0 / $pi;
+            const GRAD_FULL_TURN: Self = 400.0;
+            const GRAD_HALF_TURN: Self = 200.0;
 
             #[inline]
             fn sqrt(self) -> Self {
                 self.sqrt()
             }
             #[inline]
+            fn exp(self) -> Self {
+                self.exp()
+            }
+            #[inline]
+            fn ln(self) -> Self {
+                self.ln()
+            }
+            #[inline]
+            fn is_finite(self) -> bool {
+                self.is_finite()
+            }
+            #[inline]
             fn round(self) -> Self {
                 self.round()
             }
@@ -0,0 +1,580 @@
+use crate::{
+    Aabb2, Aabb3, Circle, Float, InnerSpace, Mat2, Mat3, Mat4, Matrix, Quat, Rad, Sphere,
+    SquareMatrix, Vec2, Vec3, Zero,
+};
+
+/// Bounding box with arbitrary orientation, given by its center, its half extents along its own
+/// axes and a rotation whose columns are those axes. The rotation is expected to be orthonormal
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb3<S> {
+    pub center: Vec3<S>,
+    pub extents: Vec3<S>,
+    pub rotation: Mat3<S>,
+}
+
+/// Rectangle with arbitrary orientation, see `Obb3`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb2<S> {
+    pub center: Vec2<S>,
+    pub extents: Vec2<S>,
+    pub rotation: Mat2<S>,
+}
+
+/// Eigenvectors of a symmetric matrix, found with the cyclic Jacobi method, as the columns of
+/// an orthonormal matrix
+fn symmetric_eigenvectors<S: Float>(m: &Mat3<S>) -> Mat3<S> {
+    let mut a = [m.x, m.y, m.z].map(|c| [c.x, c.y, c.z]);
+    let mut v = [[S::ZERO; 3]; 3];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = S::ONE;
+    }
+    let scale = a[0][0].abs() + a[1][1].abs() + a[2][2].abs();
+    for _ in 0..32 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off <= S::EPSILON * S::EPSILON * scale * scale {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == S::ZERO {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + S::ONE).sqrt());
+            let c = (t * t + S::ONE).sqrt().recip();
+            let s = t * c;
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+        }
+    }
+    let column = |j: usize| Vec3::new(v[0][j], v[1][j], v[2][j]);
+    Mat3::from_cols(column(0), column(1), column(2))
+}
+
+impl<S: Float> Obb3<S> {
+    #[inline]
+    pub const fn new(center: Vec3<S>, extents: Vec3<S>, rotation: Mat3<S>) -> Obb3<S> {
+        Obb3 {
+            center,
+            extents,
+            rotation,
+        }
+    }
+
+    /// Box rotated by a unit quaternion
+    #[inline]
+    pub fn from_quat(center: Vec3<S>, extents: Vec3<S>, rotation: Quat<S>) -> Obb3<S> {
+        Obb3::new(center, extents, rotation.into())
+    }
+
+    /// Box covering an axis-aligned box transformed by an affine transformation without shear.
+    /// Scaling is moved into the extents, axes with a zero scale get an orthonormal completion
+    pub fn from_aabb_transform(aabb: &Aabb3<S>, m: &Mat4<S>) -> Obb3<S> {
+        let (scale, rotation) = m.scale_rotation();
+        let e = aabb.extents();
+        Obb3::new(
+            m.transform_point3(aabb.center()),
+            Vec3::new(
+                e.x * scale.x.abs(),
+                e.y * scale.y.abs(),
+                e.z * scale.z.abs(),
+            ),
+            rotation,
+        )
+    }
+
+    /// Box fitted to a point set, oriented along the principal axes of their covariance.
+    /// Returns `None` if there are no points
+    pub fn from_points(points: &[Vec3<S>]) -> Option<Obb3<S>> {
+        let first = *points.first()?;
+        let count = points.iter().fold(S::ZERO, |n, _| n + S::ONE);
+        let mean = points.iter().fold(Vec3::ZERO, |sum, &p| sum + p) / count;
+        let covariance = points.iter().fold(Mat3::ZERO, |sum, &p| {
+            let d = p - mean;
+            sum + Mat3::from_cols(d * d.x, d * d.y, d * d.z)
+        });
+        let mut rotation = symmetric_eigenvectors(&covariance);
+        rotation.z = rotation.x.cross(rotation.y);
+
+        let to_local = rotation.transpose();
+        let first = to_local * first;
+        let (min, max) = points.iter().fold((first, first), |(min, max), &p| {
+            let p = to_local * p;
+            (min.min_element_wise(p), max.max_element_wise(p))
+        });
+        Some(Obb3::new(
+            rotation * ((min + max) * S::HALF),
+            (max - min) * S::HALF,
+            rotation,
+        ))
+    }
+
+    /// Corners of the box, ordered like binary numbers with the x axis in the lowest bit, from
+    /// all negative to all positive extents
+    pub fn corners(&self) -> [Vec3<S>; 8] {
+        let mut corners = [self.center; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = if i & 1 == 0 {
+                -self.extents.x
+            } else {
+                self.extents.x
+            };
+            let y = if i & 2 == 0 {
+                -self.extents.y
+            } else {
+                self.extents.y
+            };
+            let z = if i & 4 == 0 {
+                -self.extents.z
+            } else {
+                self.extents.z
+            };
+            *corner += self.rotation * Vec3::new(x, y, z);
+        }
+        corners
+    }
+
+    /// Smallest axis-aligned box containing the box
+    pub fn aabb(&self) -> Aabb3<S> {
+        let r = self.rotation;
+        let e = self.extents;
+        let extents = r.x.abs() * e.x + r.y.abs() * e.y + r.z.abs() * e.z;
+        Aabb3::from_center_extents(self.center, extents)
+    }
+
+    /// Whether the point is inside the box or on its boundary
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        let local = self.rotation.transpose() * (point - self.center);
+        local.x.abs() <= self.extents.x
+            && local.y.abs() <= self.extents.y
+            && local.z.abs() <= self.extents.z
+    }
+
+    /// Point of the box closest to `point`, which is `point` itself if it is inside
+    pub fn closest_point(&self, point: Vec3<S>) -> Vec3<S> {
+        let local = self.rotation.transpose() * (point - self.center);
+        let clamped = local
+            .max_element_wise(-self.extents)
+            .min_element_wise(self.extents);
+        self.center + self.rotation * clamped
+    }
+
+    /// Squared distance from the box to `point`, zero if the point is inside
+    #[inline]
+    pub fn squared_distance(&self, point: Vec3<S>) -> S {
+        (point - self.closest_point(point)).magnitude2()
+    }
+
+    /// Whether the boxes overlap or touch, using the separating axis theorem with the face
+    /// normals of both boxes and the cross products of their edges
+    pub fn overlaps(&self, other: &Obb3<S>) -> bool {
+        let a = [self.rotation.x, self.rotation.y, self.rotation.z];
+        let b = [other.rotation.x, other.rotation.y, other.rotation.z];
+        let ea = [self.extents.x, self.extents.y, self.extents.z];
+        let eb = [other.extents.x, other.extents.y, other.extents.z];
+        let d = other.center - self.center;
+        let t = a.map(|axis| axis.dot(d));
+        let r = a.map(|ai| b.map(|bj| ai.dot(bj)));
+        // The epsilon keeps near parallel edges, whose cross products vanish, from reporting
+        // a separation
+        let abs_r = r.map(|row| row.map(|x| x.abs() + S::EPSILON));
+
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let tb = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if tb.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let tl = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if tl.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether the box overlaps or touches an axis-aligned box
+    #[inline]
+    pub fn overlaps_aabb(&self, aabb: &Aabb3<S>) -> bool {
+        self.overlaps(&Obb3::from(*aabb))
+    }
+
+    /// Whether the box overlaps or touches a sphere
+    #[inline]
+    pub fn overlaps_sphere(&self, sphere: &Sphere<S>) -> bool {
+        self.squared_distance(sphere.center) <= sphere.radius * sphere.radius
+    }
+}
+
+impl<S: Float> From<Aabb3<S>> for Obb3<S> {
+    #[inline]
+    fn from(aabb: Aabb3<S>) -> Obb3<S> {
+        Obb3::new(
+            aabb.center(),
+            aabb.extents(),
+            Mat3::from_cols(Vec3::X, Vec3::Y, Vec3::Z),
+        )
+    }
+}
+
+impl<S: Float> Obb2<S> {
+    #[inline]
+    pub const fn new(center: Vec2<S>, extents: Vec2<S>, rotation: Mat2<S>) -> Obb2<S> {
+        Obb2 {
+            center,
+            extents,
+            rotation,
+        }
+    }
+
+    /// Rectangle rotated counterclockwise by `angle`
+    #[inline]
+    pub fn from_angle<A: Into<Rad<S>>>(center: Vec2<S>, extents: Vec2<S>, angle: A) -> Obb2<S> {
+        Obb2::new(center, extents, Mat2::from_rotation(angle))
+    }
+
+    /// Rectangle covering an axis-aligned rectangle transformed by an affine 2D transformation
+    /// without shear. Scaling is moved into the extents, an axis with a zero scale is completed
+    /// perpendicular to the other one
+    pub fn from_aabb_transform(aabb: &Aabb2<S>, m: &Mat3<S>) -> Obb2<S> {
+        let (x, y) = (m.x.truncate(), m.y.truncate());
+        let scale = Vec2::new(x.magnitude(), y.magnitude());
+        let rotation = match (scale.x != S::ZERO, scale.y != S::ZERO) {
+            (true, true) => Mat2::from_cols(x / scale.x, y / scale.y),
+            (true, false) => {
+                let x = x / scale.x;
+                Mat2::from_cols(x, Vec2::new(-x.y, x.x))
+            }
+            (false, true) => {
+                let y = y / scale.y;
+                Mat2::from_cols(Vec2::new(y.y, -y.x), y)
+            }
+            (false, false) => Mat2::IDENTITY,
+        };
+        let e = aabb.extents();
+        Obb2::new(
+            m.transform_point2(aabb.center()),
+            Vec2::new(e.x * scale.x, e.y * scale.y),
+            rotation,
+        )
+    }
+
+    /// Rectangle fitted to a point set, oriented along the principal axes of their covariance.
+    /// Returns `None` if there are no points
+    pub fn from_points(points: &[Vec2<S>]) -> Option<Obb2<S>> {
+        let first = *points.first()?;
+        let count = points.iter().fold(S::ZERO, |n, _| n + S::ONE);
+        let mean = points.iter().fold(Vec2::ZERO, |sum, &p| sum + p) / count;
+        let (xx, xy, yy) = points
+            .iter()
+            .fold((S::ZERO, S::ZERO, S::ZERO), |(xx, xy, yy), &p| {
+                let d = p - mean;
+                (xx + d.x * d.x, xy + d.x * d.y, yy + d.y * d.y)
+            });
+        let rotation = Mat2::from_rotation(Rad((xy + xy).atan2(xx - yy) * S::HALF));
+
+        let to_local = rotation.transpose();
+        let first = to_local * first;
+        let (min, max) = points.iter().fold((first, first), |(min, max), &p| {
+            let p = to_local * p;
+            (min.min_element_wise(p), max.max_element_wise(p))
+        });
+        Some(Obb2::new(
+            rotation * ((min + max) * S::HALF),
+            (max - min) * S::HALF,
+            rotation,
+        ))
+    }
+
+    /// Corners of the rectangle, ordered like binary numbers with the x axis in the lowest bit
+    pub fn corners(&self) -> [Vec2<S>; 4] {
+        let mut corners = [self.center; 4];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let x = if i & 1 == 0 {
+                -self.extents.x
+            } else {
+                self.extents.x
+            };
+            let y = if i & 2 == 0 {
+                -self.extents.y
+            } else {
+                self.extents.y
+            };
+            *corner += self.rotation * Vec2::new(x, y);
+        }
+        corners
+    }
+
+    /// Smallest axis-aligned rectangle containing the rectangle
+    pub fn aabb(&self) -> Aabb2<S> {
+        let r = self.rotation;
+        let extents = r.x.abs() * self.extents.x + r.y.abs() * self.extents.y;
+        Aabb2::from_center_extents(self.center, extents)
+    }
+
+    /// Whether the point is inside the rectangle or on its boundary
+    pub fn contains(&self, point: Vec2<S>) -> bool {
+        let local = self.rotation.transpose() * (point - self.center);
+        local.x.abs() <= self.extents.x && local.y.abs() <= self.extents.y
+    }
+
+    /// Point of the rectangle closest to `point`, which is `point` itself if it is inside
+    pub fn closest_point(&self, point: Vec2<S>) -> Vec2<S> {
+        let local = self.rotation.transpose() * (point - self.center);
+        let clamped = local
+            .max_element_wise(-self.extents)
+            .min_element_wise(self.extents);
+        self.center + self.rotation * clamped
+    }
+
+    /// Squared distance from the rectangle to `point`, zero if the point is inside
+    #[inline]
+    pub fn squared_distance(&self, point: Vec2<S>) -> S {
+        (point - self.closest_point(point)).magnitude2()
+    }
+
+    /// Whether the rectangles overlap or touch, using the separating axis theorem with the
+    /// edge normals of both rectangles
+    pub fn overlaps(&self, other: &Obb2<S>) -> bool {
+        let d = other.center - self.center;
+        let radius = |obb: &Obb2<S>, axis: Vec2<S>| {
+            obb.rotation.x.dot(axis).abs() * obb.extents.x
+                + obb.rotation.y.dot(axis).abs() * obb.extents.y
+        };
+        [
+            self.rotation.x,
+            self.rotation.y,
+            other.rotation.x,
+            other.rotation.y,
+        ]
+        .into_iter()
+        .all(|axis| d.dot(axis).abs() <= radius(self, axis) + radius(other, axis))
+    }
+
+    /// Whether the rectangle overlaps or touches an axis-aligned rectangle
+    #[inline]
+    pub fn overlaps_aabb(&self, aabb: &Aabb2<S>) -> bool {
+        self.overlaps(&Obb2::from(*aabb))
+    }
+
+    /// Whether the rectangle overlaps or touches a circle
+    #[inline]
+    pub fn overlaps_circle(&self, circle: &Circle<S>) -> bool {
+        self.squared_distance(circle.center) <= circle.radius * circle.radius
+    }
+}
+
+impl<S: Float> From<Aabb2<S>> for Obb2<S> {
+    #[inline]
+    fn from(aabb: Aabb2<S>) -> Obb2<S> {
+        Obb2::new(
+            aabb.center(),
+            aabb.extents(),
+            Mat2::from_cols(Vec2::X, Vec2::Y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Aabb2, Aabb3, Circle, Deg, InnerSpace, Mat2, Mat3, Mat4, Obb2, Obb3, Quat, Sphere,
+        SquareMatrix, Vec2, Vec3,
+    };
+
+    fn obb() -> Obb3<f64> {
+        Obb3::from_quat(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(2.0, 1.0, 0.5),
+            Quat::from_axis_angle(Vec3::Z, Deg(45.0)),
+        )
+    }
+
+    #[test]
+    fn test_from_aabb_transform() {
+        let aabb = Aabb3::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 2.0, 3.0));
+        let m = Mat4::from_translation(Vec3::new(1.0, -2.0, 0.5))
+            * Mat4::from_rotation(Vec3::new(1.0, 2.0, 2.0) / 3.0, Deg(40.0))
+            * Mat4::from_nonuniform_scale(2.0, 0.5, 1.5);
+        let obb = Obb3::from_aabb_transform(&aabb, &m);
+        assert_approx_eq!(obb.extents, Vec3::new(4.0, 0.5, 0.75), 1e-12);
+        assert!(obb.rotation.is_orthonormal(1e-12));
+        let corners = obb.corners();
+        assert_approx_eq!(corners[0], m.transform_point3(aabb.min), 1e-12);
+        assert_approx_eq!(corners[7], m.transform_point3(aabb.max), 1e-12);
+        let bounds = obb.aabb();
+        assert_approx_eq!(bounds.min, aabb.transform(&m).min, 1e-12);
+        assert_approx_eq!(bounds.max, aabb.transform(&m).max, 1e-12);
+
+        let rect = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 1.0));
+        let m = Mat3::from_translation(Vec2::new(1.0, 1.0)) * Mat3::from_rotation_z(Deg(90.0));
+        let obb = Obb2::from_aabb_transform(&rect, &m);
+        assert_approx_eq!(obb.center, Vec2::new(0.5, 2.0), 1e-12);
+        assert_approx_eq!(obb.extents, Vec2::new(1.0, 0.5), 1e-12);
+        assert_approx_eq!(obb.aabb().min, rect.transform(&m).min, 1e-12);
+    }
+
+    #[test]
+    fn test_from_aabb_transform_zero_scale() {
+        // Flattened boxes keep an orthonormal rotation instead of dividing by zero
+        let aabb = Aabb3::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 2.0, 3.0));
+        let rotation = Mat4::from_rotation(Vec3::new(1.0, 2.0, 2.0) / 3.0, Deg(40.0));
+        for (x, y, z) in [(2.0, 0.0, 1.5), (0.0, 0.0, 1.5), (0.0, 0.0, 0.0)] {
+            let m = rotation * Mat4::from_nonuniform_scale(x, y, z);
+            let obb = Obb3::from_aabb_transform(&aabb, &m);
+            assert!(obb.rotation.is_orthonormal(1e-12));
+            assert_approx_eq!(obb.extents, Vec3::new(2.0 * x, y, 0.5 * z), 1e-12);
+            let bounds = obb.aabb();
+            assert_approx_eq!(bounds.min, aabb.transform(&m).min, 1e-12);
+            assert_approx_eq!(bounds.max, aabb.transform(&m).max, 1e-12);
+        }
+
+        let rect = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 1.0));
+        for (x, y) in [(0.0, 2.0), (3.0, 0.0), (0.0, 0.0)] {
+            let m = Mat3::from_rotation_z(Deg(30.0)) * Mat3::from_nonuniform_scale(x, y, 1.0);
+            let obb = Obb2::from_aabb_transform(&rect, &m);
+            assert_approx_eq!(obb.rotation.determinant(), 1.0, 1e-12);
+            assert_approx_eq!(obb.extents, Vec2::new(x, 0.5 * y), 1e-12);
+            assert_approx_eq!(obb.aabb().min, rect.transform(&m).min, 1e-12);
+            assert_approx_eq!(obb.aabb().max, rect.transform(&m).max, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let obb = obb();
+        let diagonal = Vec3::new(1.0, 1.0, 0.0) * 0.5f64.sqrt();
+        let inside = obb.center + diagonal * 1.5;
+        assert!(obb.contains(inside));
+        assert_approx_eq!(obb.closest_point(inside), inside, 1e-12);
+        let outside = obb.center + diagonal * 5.0 + Vec3::Z;
+        assert!(!obb.contains(outside));
+        assert_approx_eq!(
+            obb.closest_point(outside),
+            obb.center + diagonal * 2.0 + Vec3::Z * 0.5,
+            1e-12
+        );
+        assert_approx_eq!(obb.squared_distance(outside), 9.25, 1e-12);
+        assert!(obb.overlaps_sphere(&Sphere::new(outside, 3.1)));
+        assert!(!obb.overlaps_sphere(&Sphere::new(outside, 3.0)));
+
+        let rect = Obb2::from_angle(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0), Deg(90.0));
+        assert!(rect.contains(Vec2::new(1.5, 1.5)));
+        assert!(!rect.contains(Vec2::new(2.5, 0.0)));
+        assert_approx_eq!(
+            rect.closest_point(Vec2::new(3.0, 5.0)),
+            Vec2::new(2.0, 2.0),
+            1e-12
+        );
+        assert!(rect.overlaps_circle(&Circle::new(Vec2::new(3.0, 0.0), 1.0)));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let a = Obb3::from(Aabb3::new(
+            Vec3::new(-1.0, -1.0, -1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ));
+        let rotation = Mat3::from_rotation_z(Deg(45.0));
+        let b = Obb3::new(Vec3::new(2.3, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0), rotation);
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        let b = Obb3::new(Vec3::new(2.5, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0), rotation);
+        assert!(!a.overlaps(&b));
+        // Compare against projecting the corners onto all candidate axes
+        let reference = |a: &Obb3<f64>, b: &Obb3<f64>| {
+            let axes_a = [a.rotation.x, a.rotation.y, a.rotation.z];
+            let axes_b = [b.rotation.x, b.rotation.y, b.rotation.z];
+            let mut axes = vec![];
+            axes.extend(axes_a);
+            axes.extend(axes_b);
+            for i in axes_a {
+                for j in axes_b {
+                    axes.push(i.cross(j));
+                }
+            }
+            axes.into_iter()
+                .filter(|axis| axis.magnitude2() > 1e-9)
+                .all(|axis| {
+                    let project = |o: &Obb3<f64>| {
+                        let d = o.corners().map(|c| c.dot(axis));
+                        let min = d.iter().copied().fold(f64::INFINITY, f64::min);
+                        let max = d.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                        (min, max)
+                    };
+                    let ((a0, a1), (b0, b1)) = (project(a), project(b));
+                    a0 <= b1 && b0 <= a1
+                })
+        };
+        for i in 0..200 {
+            let f = i as f64;
+            let rotation = Mat3::from_rotation_x(Deg(f * 37.0))
+                * Mat3::from_rotation_y(Deg(f * 53.0))
+                * Mat3::from_rotation_z(Deg(f * 11.0));
+            let center = Vec3::new((f * 0.7).sin(), (f * 1.3).cos(), (f * 0.4).sin()) * 3.2;
+            let b = Obb3::new(center, Vec3::new(1.0, 0.5, 1.5), rotation);
+            assert_eq!(a.overlaps(&b), reference(&a, &b));
+            assert_eq!(b.overlaps(&a), reference(&a, &b));
+        }
+        assert!(a.overlaps(&a));
+        assert!(obb().overlaps_aabb(&Aabb3::new(
+            Vec3::new(2.0, 3.0, 3.0),
+            Vec3::new(4.0, 4.0, 4.0)
+        )));
+        assert!(!obb().overlaps_aabb(&Aabb3::new(
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(4.0, 1.0, 4.0)
+        )));
+
+        let r = Obb2::from(Aabb2::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0)));
+        let s = Obb2::from_angle(Vec2::new(2.3, 0.0), Vec2::new(1.0, 1.0), Deg(45.0));
+        assert!(r.overlaps(&s));
+        let s = Obb2::from_angle(Vec2::new(2.5, 0.0), Vec2::new(1.0, 1.0), Deg(45.0));
+        assert!(!r.overlaps(&s));
+        assert!(!s.overlaps_aabb(&Aabb2::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0))));
+    }
+
+    #[test]
+    fn test_from_points() {
+        let expected = obb();
+        let obb = Obb3::from_points(&expected.corners()).unwrap();
+        assert_approx_eq!(obb.center, expected.center, 1e-9);
+        assert!(obb.rotation.is_orthonormal(1e-9));
+        for corner in expected.corners() {
+            assert!(obb.squared_distance(corner) < 1e-18);
+        }
+        let volume = |o: &Obb3<f64>| o.extents.x * o.extents.y * o.extents.z;
+        assert_approx_eq!(volume(&obb), volume(&expected), 1e-9);
+        assert_eq!(Obb3::<f64>::from_points(&[]), None);
+        let single = Obb3::from_points(&[Vec3::new(1.0, 2.0, 3.0)]).unwrap();
+        assert_eq!(single.extents, Vec3::new(0.0, 0.0, 0.0));
+
+        let rect = Obb2::new(
+            Vec2::new(1.0, -2.0),
+            Vec2::new(3.0, 1.0),
+            Mat2::from_rotation(Deg(30.0)),
+        );
+        let fitted = Obb2::from_points(&rect.corners()).unwrap();
+        assert_approx_eq!(fitted.center, rect.center, 1e-12);
+        assert_approx_eq!(fitted.extents.x * fitted.extents.y, 3.0, 1e-12);
+    }
+}
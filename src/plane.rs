@@ -0,0 +1,223 @@
+use crate::{Float, InnerSpace, Vec3, Vec4};
+
+/// Side of a plane a point lies on, see `Plane::classify`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Side {
+    /// On the side the normal points to
+    Front,
+    Back,
+    /// Within the tolerance of the plane
+    On,
+}
+
+/// Plane of the points `p` with `normal.dot(p) + d == 0`.
+///
+/// The normal is expected to be a unit vector, so that `d` is the negated distance of the plane
+/// from the origin along the normal and `signed_distance` gives actual distances. The `Vec4`
+/// form stores the normal in `xyz` and `d` in `w`, like `FrustumPlanes`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plane<S> {
+    pub normal: Vec3<S>,
+    pub d: S,
+}
+
+impl<S: Float> Plane<S> {
+    #[inline]
+    pub const fn new(normal: Vec3<S>, d: S) -> Plane<S> {
+        Plane { normal, d }
+    }
+
+    /// Plane through `point` with the given normal, which is normalized
+    #[inline]
+    pub fn from_point_normal(point: Vec3<S>, normal: Vec3<S>) -> Plane<S> {
+        let normal = normal.normalize();
+        Plane::new(normal, -normal.dot(point))
+    }
+
+    /// Plane through three points, with the normal facing the side from which they appear in
+    /// counterclockwise order. Returns `None` if the points are collinear
+    pub fn from_points(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>) -> Option<Plane<S>> {
+        let normal = (b - a).cross(c - a);
+        let length = normal.magnitude();
+        if length == S::ZERO || !length.is_finite() {
+            return None;
+        }
+        Some(Plane::from_point_normal(a, normal / length))
+    }
+
+    /// Same plane with a unit normal, for planes built from an unnormalized `Vec4`
+    #[inline]
+    pub fn normalize(&self) -> Plane<S> {
+        let length = self.normal.magnitude();
+        Plane::new(self.normal / length, self.d / length)
+    }
+
+    /// Same plane with the normal pointing the other way
+    #[inline]
+    pub fn flip(&self) -> Plane<S> {
+        Plane::new(-self.normal, -self.d)
+    }
+
+    /// Distance of `point` from the plane, positive on the side the normal points to
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3<S>) -> S {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Side of the plane `point` lies on, treating points closer than `tolerance` as on it
+    pub fn classify(&self, point: Vec3<S>, tolerance: S) -> Side {
+        let distance = self.signed_distance(point);
+        if distance > tolerance {
+            Side::Front
+        } else if distance < -tolerance {
+            Side::Back
+        } else {
+            Side::On
+        }
+    }
+
+    /// Point of the plane closest to `point`
+    #[inline]
+    pub fn project_point(&self, point: Vec3<S>) -> Vec3<S> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Mirror image of `point` on the other side of the plane
+    #[inline]
+    pub fn reflect_point(&self, point: Vec3<S>) -> Vec3<S> {
+        point - self.normal * (self.signed_distance(point) * (S::ONE + S::ONE))
+    }
+
+    /// Mirror image of a direction, which ignores the position of the plane
+    #[inline]
+    pub fn reflect_vector(&self, vector: Vec3<S>) -> Vec3<S> {
+        vector - self.normal * (self.normal.dot(vector) * (S::ONE + S::ONE))
+    }
+
+    /// Point where the line through `point` with direction `dir` crosses the plane, or `None`
+    /// if the line is parallel to it
+    pub fn intersect_line(&self, point: Vec3<S>, dir: Vec3<S>) -> Option<Vec3<S>> {
+        let denom = self.normal.dot(dir);
+        if denom == S::ZERO {
+            return None;
+        }
+        Some(point - dir * (self.signed_distance(point) / denom))
+    }
+
+    /// Line along which two planes meet, as a point on it and its direction, or `None` if the
+    /// planes are parallel
+    pub fn intersect_plane(&self, other: &Plane<S>) -> Option<(Vec3<S>, Vec3<S>)> {
+        let dir = self.normal.cross(other.normal);
+        let det = dir.magnitude2();
+        if det <= S::EPSILON * self.normal.magnitude2() * other.normal.magnitude2() {
+            return None;
+        }
+        let point = (self.normal * other.d - other.normal * self.d).cross(dir) / det;
+        Some((point, dir))
+    }
+
+    /// Single point shared by three planes, or `None` if any two of them are parallel or they
+    /// meet along a line
+    pub fn intersect_planes(a: &Plane<S>, b: &Plane<S>, c: &Plane<S>) -> Option<Vec3<S>> {
+        let bc = b.normal.cross(c.normal);
+        let det = a.normal.dot(bc);
+        let scale = a.normal.magnitude2() * b.normal.magnitude2() * c.normal.magnitude2();
+        if det * det <= S::EPSILON * S::EPSILON * scale {
+            return None;
+        }
+        let point =
+            (bc * -a.d - c.normal.cross(a.normal) * b.d - a.normal.cross(b.normal) * c.d) / det;
+        Some(point)
+    }
+}
+
+impl<S: Float> From<Vec4<S>> for Plane<S> {
+    #[inline]
+    fn from(v: Vec4<S>) -> Plane<S> {
+        Plane::new(v.truncate(), v.w)
+    }
+}
+
+impl<S: Float> From<Plane<S>> for Vec4<S> {
+    #[inline]
+    fn from(plane: Plane<S>) -> Vec4<S> {
+        plane.normal.extend(plane.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mat4, Plane, Side, SquareMatrix, Vec3, Vec4, Zero};
+
+    fn plane() -> Plane<f64> {
+        Plane::from_point_normal(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 3.0, 0.0))
+    }
+
+    #[test]
+    fn test_construction() {
+        let p = plane();
+        assert_eq!(p, Plane::new(Vec3::Y, -2.0));
+        let q = Plane::from_points(
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(0.0, 2.0, 1.0),
+        );
+        assert_approx_eq!(q.unwrap().normal, Vec3::Y, 1e-12);
+        assert_approx_eq!(q.unwrap().d, -2.0, 1e-12);
+        let collinear = Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::X * 2.0);
+        assert_eq!(collinear, None);
+
+        let v: Vec4<f64> = p.into();
+        assert_eq!(v, Vec4::new(0.0, 1.0, 0.0, -2.0));
+        assert_eq!(Plane::from(v), p);
+        assert_eq!(Plane::from(v * 2.0).normalize(), p);
+        assert_eq!(p.flip(), Plane::new(-Vec3::Y, 2.0));
+    }
+
+    #[test]
+    fn test_distance() {
+        let p = plane();
+        let point = Vec3::new(1.0, 5.0, -3.0);
+        assert_eq!(p.signed_distance(point), 3.0);
+        assert_eq!(p.classify(point, 1e-9), Side::Front);
+        assert_eq!(p.classify(-point, 1e-9), Side::Back);
+        assert_eq!(p.classify(Vec3::new(4.0, 2.0, 1.0), 1e-9), Side::On);
+        assert_eq!(p.project_point(point), Vec3::new(1.0, 2.0, -3.0));
+        assert_eq!(p.reflect_point(point), Vec3::new(1.0, -1.0, -3.0));
+        assert_eq!(p.reflect_vector(point), Vec3::new(1.0, -5.0, -3.0));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let p = plane();
+        let hit = p.intersect_line(Vec3::new(1.0, 0.0, 1.0), Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(hit, Some(Vec3::new(3.0, 2.0, 1.0)));
+        assert_eq!(p.intersect_line(Vec3::ZERO, Vec3::X), None);
+
+        let q = Plane::new(Vec3::X, -1.0);
+        let (point, dir) = p.intersect_plane(&q).unwrap();
+        assert_approx_eq!(p.signed_distance(point), 0.0, 1e-12);
+        assert_approx_eq!(q.signed_distance(point), 0.0, 1e-12);
+        assert_approx_eq!(dir, -Vec3::Z, 1e-12);
+        assert_eq!(p.intersect_plane(&p.flip()), None);
+
+        let r = Plane::from_point_normal(Vec3::new(0.0, 0.0, 4.0), Vec3::new(0.0, 1.0, 1.0));
+        let point = Plane::intersect_planes(&p, &q, &r).unwrap();
+        assert_approx_eq!(point, Vec3::new(1.0, 2.0, 2.0), 1e-12);
+        assert_eq!(Plane::intersect_planes(&p, &q, &p.flip()), None);
+        // Unnormalized planes with short normals still meet in a point
+        let [p, q, r] = [p, q, r].map(|plane| Plane::new(plane.normal * 1e-6, plane.d * 1e-6));
+        let point = Plane::intersect_planes(&p, &q, &r).unwrap();
+        assert_approx_eq!(point, Vec3::new(1.0, 2.0, 2.0), 1e-9);
+    }
+
+    #[test]
+    fn test_reflection_matrix() {
+        let p = Plane::from_point_normal(Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, -2.0, 2.0));
+        let m = Mat4::from_reflection(&p);
+        let point = Vec3::new(-2.0, 0.5, 4.0);
+        assert_approx_eq!(m.transform_point3(point), p.reflect_point(point), 1e-12);
+        assert_approx_eq!(m.transform_vector3(point), p.reflect_vector(point), 1e-12);
+        assert_approx_eq!(m * m, Mat4::IDENTITY, 1e-12);
+    }
+}
@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+use crate::{Float, Vec3};
+
+/// Side of the plane through `a`, `b` and `c` that `p` lies on, `Greater` on the side the
+/// normal `(b - a).cross(c - a)` points to. Exact for any finite input, following Shewchuk's
+/// adaptive predicates: the floating point determinant is used when its error bound proves
+/// the sign, otherwise the determinant is evaluated exactly
+pub(crate) fn orientation<S: Float>(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>, p: Vec3<S>) -> Ordering {
+    let (ad, bd, cd) = (a - p, b - p, c - p);
+    let (bc, cb) = (bd.x * cd.y, cd.x * bd.y);
+    let (ca, ac) = (cd.x * ad.y, ad.x * cd.y);
+    let (ab, ba) = (ad.x * bd.y, bd.x * ad.y);
+    let det = ad.z * (bc - cb) + bd.z * (ca - ac) + cd.z * (ab - ba);
+    let permanent = (bc.abs() + cb.abs()) * ad.z.abs()
+        + (ca.abs() + ac.abs()) * bd.z.abs()
+        + (ab.abs() + ba.abs()) * cd.z.abs();
+    let four = (S::ONE + S::ONE) * (S::ONE + S::ONE);
+    let bound = four * S::EPSILON * permanent;
+    let det = if det > bound || -det > bound {
+        det
+    } else {
+        exact_determinant(a, b, c, p)
+    };
+    // The determinant has rows `a - p`, `b - p` and `c - p`, positive below the plane
+    S::ZERO.partial_cmp(&det).unwrap_or(Ordering::Equal)
+}
+
+/// Sign carrying value of the determinant with rows `a - p`, `b - p` and `c - p`, computed
+/// with expansions, sums of floats that represent a number without rounding
+fn exact_determinant<S: Float>(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>, p: Vec3<S>) -> S {
+    let difference = |x: S, y: S| {
+        let (sum, error) = two_sum(x, -y);
+        vec![error, sum]
+    };
+    let [ax, ay, az] = [(a.x, p.x), (a.y, p.y), (a.z, p.z)].map(|(x, y)| difference(x, y));
+    let [bx, by, bz] = [(b.x, p.x), (b.y, p.y), (b.z, p.z)].map(|(x, y)| difference(x, y));
+    let [cx, cy, cz] = [(c.x, p.x), (c.y, p.y), (c.z, p.z)].map(|(x, y)| difference(x, y));
+    let minor = |p: &[S], q: &[S], r: &[S], s: &[S]| sum(&product(p, q), &negate(&product(r, s)));
+    let det = sum(
+        &sum(
+            &product(&az, &minor(&bx, &cy, &cx, &by)),
+            &product(&bz, &minor(&cx, &ay, &ax, &cy)),
+        ),
+        &product(&cz, &minor(&ax, &by, &bx, &ay)),
+    );
+    // The components grow in magnitude, so the last one has the sign of the sum
+    det.last().copied().unwrap_or(S::ZERO)
+}
+
+/// Rounded sum and its rounding error, which add up to `a + b` exactly
+#[inline]
+fn two_sum<S: Float>(a: S, b: S) -> (S, S) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Rounded product and its rounding error, with Dekker's splitting into half-width parts
+#[inline]
+fn two_product<S: Float>(a: S, b: S) -> (S, S) {
+    let x = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = x - a_high * b_high - a_low * b_high - a_high * b_low;
+    (x, a_low * b_low - error)
+}
+
+/// Parts of `a` with at most half of the significand bits each, so their products are exact
+#[inline]
+fn split<S: Float>(a: S) -> (S, S) {
+    // 2^ceil(p / 2) + 1 for a significand of p bits, where the epsilon is 2^(1 - p)
+    let two = S::ONE + S::ONE;
+    let mut splitter = S::ONE;
+    while splitter * splitter * S::EPSILON < two {
+        splitter *= two;
+    }
+    let c = (splitter + S::ONE) * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+/// Exact sum of an expansion and a float, dropping zero components
+fn grow<S: Float>(e: &[S], b: S) -> Vec<S> {
+    let mut result = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e {
+        let (sum, error) = two_sum(q, component);
+        if error != S::ZERO {
+            result.push(error);
+        }
+        q = sum;
+    }
+    if q != S::ZERO {
+        result.push(q);
+    }
+    result
+}
+
+fn sum<S: Float>(e: &[S], f: &[S]) -> Vec<S> {
+    f.iter()
+        .fold(e.to_vec(), |sum, &component| grow(&sum, component))
+}
+
+fn negate<S: Float>(e: &[S]) -> Vec<S> {
+    e.iter().map(|&component| -component).collect()
+}
+
+fn product<S: Float>(e: &[S], f: &[S]) -> Vec<S> {
+    let mut result = Vec::new();
+    for &x in e {
+        for &y in f {
+            let (product, error) = two_product(x, y);
+            result = grow(&grow(&result, error), product);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::orientation;
+    use crate::Vec3;
+
+    #[test]
+    fn test_orientation() {
+        let a = Vec3::new(0.1, 0.7, 0.3);
+        let b = Vec3::new(1e10, 0.3, 0.3);
+        let c = Vec3::new(0.3, -1e-10, 0.3);
+        let p = Vec3::new(1.0 / 3.0, 1.0 / 7.0, 0.3);
+        // Seen from above the triangle is clockwise, so its normal points down
+        assert_eq!(orientation(a, b, c, p), Ordering::Equal);
+        let up = Vec3::new(p.x, p.y, f64::from_bits(0.3f64.to_bits() + 1));
+        let down = Vec3::new(p.x, p.y, f64::from_bits(0.3f64.to_bits() - 1));
+        assert_eq!(orientation(a, b, c, up), Ordering::Less);
+        assert_eq!(orientation(a, b, c, down), Ordering::Greater);
+        assert_eq!(orientation(a, c, b, up), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_orientation_exact() {
+        // Integers with products too wide for a float. The fourth point is on the plane of
+        // the other three, or one unit off it along a random axis
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut random = |bits: u32| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> (64 - bits)) as i64 - (1 << (bits - 1))
+        };
+        for i in 0..20000 {
+            // Half of the cases in f32, with points small enough to be exact in it
+            let bits = if i % 2 == 0 { 31 } else { 12 };
+            let mut point = || [random(bits), random(bits), random(bits)];
+            let (a, b, c) = (point(), point(), point());
+            let (m, n) = (random(3), random(3));
+            let p: [i64; 3] = std::array::from_fn(|k| {
+                a[k] + m * (b[k] - a[k]) + n * (c[k] - a[k]) + random(2).signum()
+            });
+            let rows = [a, b, c].map(|q| q.map(|x| x as i128));
+            let p = p.map(|x| x as i128);
+            let [u, v, w] = rows.map(|q| [q[0] - p[0], q[1] - p[1], q[2] - p[2]]);
+            let det = u[0] * (v[1] * w[2] - v[2] * w[1]) - u[1] * (v[0] * w[2] - v[2] * w[0])
+                + u[2] * (v[0] * w[1] - v[1] * w[0]);
+            let [a, b, c, p] = [rows[0], rows[1], rows[2], p];
+            let side = if bits == 31 {
+                let [a, b, c, p] =
+                    [a, b, c, p].map(|q| Vec3::new(q[0] as f64, q[1] as f64, q[2] as f64));
+                orientation(a, b, c, p)
+            } else {
+                let [a, b, c, p] =
+                    [a, b, c, p].map(|q| Vec3::new(q[0] as f32, q[1] as f32, q[2] as f32));
+                orientation(a, b, c, p)
+            };
+            assert_eq!(side, 0.cmp(&det));
+        }
+    }
+}
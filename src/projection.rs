@@ -1,4 +1,4 @@
-use crate::{Angle, Float, Mat4, Rad};
+use crate::{Angle, Float, InnerSpace, Mat4, Rad, Vec2, Vec3};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Perspective<S> {
@@ -8,19 +8,464 @@ pub struct Perspective<S> {
     pub far: S,
 }
 
-impl<S: Float> From<Perspective<S>> for Mat4<S> {
+/// Perspective projection with the far plane placed at infinity
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PerspectiveInfinite<S> {
+    pub fovy: Rad<S>,
+    pub aspect: S,
+    pub near: S,
+}
+
+/// Reverses the depth mapping of the wrapped perspective projection so that the near plane
+/// maps to depth 1 and the far plane to depth 0. Combined with a floating point depth buffer
+/// this distributes the depth precision much more evenly
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ReverseZ<P>(pub P);
+
+/// Range of the normalized device depth coordinate
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DepthRange {
+    /// Depth in `[-1, 1]`, used by OpenGL
+    NegativeOneToOne,
+    /// Depth in `[0, 1]`, used by Direct3D, Vulkan, Metal and WebGPU
+    ZeroToOne,
+}
+
+/// Handedness of the view space. A right-handed view looks down the negative z axis,
+/// a left-handed view looks down the positive z axis
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Handedness {
+    Right,
+    Left,
+}
+
+/// Convention used when building a projection matrix: the view space handedness, the range of
+/// the depth coordinate and the direction of the y axis in normalized device coordinates
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ClipSpace {
+    pub handedness: Handedness,
+    pub depth: DepthRange,
+    /// Y axis pointing down in normalized device coordinates
+    pub flip_y: bool,
+}
+
+impl ClipSpace {
+    pub const OPENGL: ClipSpace = ClipSpace {
+        handedness: Handedness::Right,
+        depth: DepthRange::NegativeOneToOne,
+        flip_y: false,
+    };
+
+    pub const DIRECT3D: ClipSpace = ClipSpace {
+        handedness: Handedness::Left,
+        depth: DepthRange::ZeroToOne,
+        flip_y: false,
+    };
+
+    pub const WEBGPU: ClipSpace = ClipSpace {
+        handedness: Handedness::Right,
+        depth: DepthRange::ZeroToOne,
+        flip_y: false,
+    };
+
+    pub const VULKAN: ClipSpace = ClipSpace {
+        handedness: Handedness::Right,
+        depth: DepthRange::ZeroToOne,
+        flip_y: true,
+    };
+
+    /// Convention shared by the `From<_> for Mat4` conversions of every projection type.
+    /// Use `Projection::to_mat4` to build a matrix for any other convention
+    pub const DEFAULT: ClipSpace = ClipSpace::VULKAN;
+
+    #[inline]
+    pub const fn with_handedness(self, handedness: Handedness) -> ClipSpace {
+        ClipSpace { handedness, ..self }
+    }
+
+    /// Depth of the near plane in normalized device coordinates
+    #[inline]
+    fn near_depth<S: Float>(&self) -> S {
+        match self.depth {
+            DepthRange::NegativeOneToOne => -S::ONE,
+            DepthRange::ZeroToOne => S::ZERO,
+        }
+    }
+
+    /// Converts a matrix built for a right-handed view space with y up
+    fn apply<S: Float>(&self, mut m: Mat4<S>) -> Mat4<S> {
+        if self.flip_y {
+            m.x.y = -m.x.y;
+            m.y.y = -m.y.y;
+            m.z.y = -m.z.y;
+            m.w.y = -m.w.y;
+        }
+        if self.handedness == Handedness::Left {
+            m.z = -m.z;
+        }
+        m
+    }
+
+    /// Inverse of `apply`, converts an inverse matrix built for a right-handed view space
+    /// with y up
+    fn apply_inverse<S: Float>(&self, mut m: Mat4<S>) -> Mat4<S> {
+        if self.flip_y {
+            m.y = -m.y;
+        }
+        if self.handedness == Handedness::Left {
+            m.x.z = -m.x.z;
+            m.y.z = -m.y.z;
+            m.z.z = -m.z.z;
+            m.w.z = -m.w.z;
+        }
+        m
+    }
+}
+
+/// Common interface of the projection types. `matrix` and everything derived from it agree
+/// exactly with the `From<_> for Mat4` conversion of the implementing type, which uses the
+/// `CLIP_SPACE` convention. `project` and `unproject` work in view space, their `_world`
+/// variants take a view matrix and work in world space
+pub trait Projection<S: Float> {
+    /// Convention used by `matrix` and the `From<_> for Mat4` conversion
+    const CLIP_SPACE: ClipSpace = ClipSpace::DEFAULT;
+
+    /// Projection matrix for the given clip space convention
+    fn to_mat4(&self, clip: ClipSpace) -> Mat4<S>;
+
+    /// Inverse of the projection matrix for the given clip space convention,
+    /// computed analytically
+    fn to_inverse_mat4(&self, clip: ClipSpace) -> Mat4<S>;
+
+    #[inline]
+    fn matrix(&self) -> Mat4<S> {
+        self.to_mat4(Self::CLIP_SPACE)
+    }
+
+    #[inline]
+    fn inverse_matrix(&self) -> Mat4<S> {
+        self.to_inverse_mat4(Self::CLIP_SPACE)
+    }
+
+    /// Project a view space point to normalized device coordinates, see `project_world`
+    #[inline]
+    fn project(&self, point: Vec3<S>) -> Vec3<S> {
+        self.matrix().transform_point3(point)
+    }
+
+    /// Transform normalized device coordinates back to view space, see `unproject_world`
+    #[inline]
+    fn unproject(&self, ndc: Vec3<S>) -> Vec3<S> {
+        self.inverse_matrix().transform_point3(ndc)
+    }
+
+    /// Project a world space point seen through the affine `view` matrix to normalized device
+    /// coordinates
+    #[inline]
+    fn project_world(&self, view: &Mat4<S>, point: Vec3<S>) -> Vec3<S> {
+        self.project(view.transform_point3(point))
+    }
+
+    /// Transform normalized device coordinates back to world space through the affine `view`
+    /// matrix. Returns `None` if `view` is not invertible
+    #[inline]
+    fn unproject_world(&self, view: &Mat4<S>, ndc: Vec3<S>) -> Option<Vec3<S>> {
+        let inv_view = view.inverse_affine()?;
+        Some(inv_view.transform_point3(self.unproject(ndc)))
+    }
+
+    /// Distance along the view direction of a point with the given normalized device depth
+    fn linearize_depth(&self, depth: S) -> S {
+        let inv = self.inverse_matrix();
+        let z = inv.z.z * depth + inv.w.z;
+        let w = inv.z.w * depth + inv.w.w;
+        match Self::CLIP_SPACE.handedness {
+            Handedness::Right => -z / w,
+            Handedness::Left => z / w,
+        }
+    }
+
+    /// Ray through the given pixel of a viewport of the given size, in the space of the inverse
+    /// of `view`. The pixel origin is the top-left corner of the viewport. Returns the ray origin
+    /// on the near plane and its normalized direction, or `None` if `view` is not invertible
+    fn screen_ray(
+        &self,
+        view: &Mat4<S>,
+        pixel: Vec2<S>,
+        viewport: Vec2<S>,
+    ) -> Option<(Vec3<S>, Vec3<S>)> {
+        let two = S::ONE + S::ONE;
+        let x = two * pixel.x / viewport.x - S::ONE;
+        let y = S::ONE - two * pixel.y / viewport.y;
+        let y = if Self::CLIP_SPACE.flip_y { -y } else { y };
+
+        // The near plane is at whichever end of the depth range is closer to the viewer,
+        // which differs for reversed depth
+        let low: S = Self::CLIP_SPACE.near_depth();
+        let near_depth = if self.linearize_depth(low) < self.linearize_depth(S::ONE) {
+            low
+        } else {
+            S::ONE
+        };
+        let middle = (low + S::ONE) * S::HALF;
+
+        let inv = self.inverse_matrix();
+        let origin = inv.transform_point3(Vec3::new(x, y, near_depth));
+        let target = inv.transform_point3(Vec3::new(x, y, middle));
+
+        let inv_view = view.inverse_affine()?;
+        let direction = inv_view.transform_vector3(target - origin).normalize();
+        Some((inv_view.transform_point3(origin), direction))
+    }
+}
+
+/// Perspective matrices share the same structure and differ only in the depth coefficients
+/// `a` and `b` mapping right-handed view space z to clip space z as `a * z + b`, given the
+/// normalized device depth of the near plane
+trait PerspectiveDepth<S: Float> {
+    fn fovy(&self) -> Rad<S>;
+    fn aspect(&self) -> S;
+    fn depth(&self, near_depth: S) -> (S, S);
+}
+
+impl<S: Float> PerspectiveDepth<S> for Perspective<S> {
+    fn fovy(&self) -> Rad<S> {
+        self.fovy
+    }
+
+    fn aspect(&self) -> S {
+        self.aspect
+    }
+
+    fn depth(&self, near_depth: S) -> (S, S) {
+        finite_depth(self.near, self.far, near_depth)
+    }
+}
+
+impl<S: Float> PerspectiveDepth<S> for PerspectiveInfinite<S> {
+    fn fovy(&self) -> Rad<S> {
+        self.fovy
+    }
+
+    fn aspect(&self) -> S {
+        self.aspect
+    }
+
+    fn depth(&self, near_depth: S) -> (S, S) {
+        (-S::ONE, self.near * (near_depth - S::ONE))
+    }
+}
+
+impl<S: Float, P: PerspectiveDepth<S>> PerspectiveDepth<S> for ReverseZ<P> {
+    fn fovy(&self) -> Rad<S> {
+        self.0.fovy()
+    }
+
+    fn aspect(&self) -> S {
+        self.0.aspect()
+    }
+
+    fn depth(&self, near_depth: S) -> (S, S) {
+        // z' = (near_depth + 1) * w - z, where w = -z_view
+        let (a, b) = self.0.depth(near_depth);
+        (-(near_depth + S::ONE) - a, -b)
+    }
+}
+
+fn finite_depth<S: Float>(near: S, far: S, near_depth: S) -> (S, S) {
+    let a = -(far - near_depth * near) / (far - near);
+    (a, near * (near_depth + a))
+}
+
+#[rustfmt::skip]
+fn perspective_matrix<S: Float>(persp: &impl PerspectiveDepth<S>, clip: ClipSpace) -> Mat4<S> {
+    let half_fov = persp.fovy() * S::HALF;
+    let f = half_fov.cos() / half_fov.sin();
+    let (a, b) = persp.depth(clip.near_depth());
+
+    clip.apply(Mat4::new(
+        f / persp.aspect(), S::ZERO, S::ZERO, S::ZERO,
+        S::ZERO, f, S::ZERO, S::ZERO,
+        S::ZERO, S::ZERO, a, -S::ONE,
+        S::ZERO, S::ZERO, b, S::ZERO,
+    ))
+}
+
+#[rustfmt::skip]
+fn perspective_inverse<S: Float>(persp: &impl PerspectiveDepth<S>, clip: ClipSpace) -> Mat4<S> {
+    let half_fov = persp.fovy() * S::HALF;
+    let f = half_fov.cos() / half_fov.sin();
+    let (a, b) = persp.depth(clip.near_depth());
+
+    clip.apply_inverse(Mat4::new(
+        persp.aspect() / f, S::ZERO, S::ZERO, S::ZERO,
+        S::ZERO, S::ONE / f, S::ZERO, S::ZERO,
+        S::ZERO, S::ZERO, S::ZERO, S::ONE / b,
+        S::ZERO, S::ZERO, -S::ONE, a / b,
+    ))
+}
+
+impl<S: Float> Perspective<S> {
+    /// Same projection with the far plane moved to infinity
+    #[inline]
+    pub fn infinite(self) -> PerspectiveInfinite<S> {
+        PerspectiveInfinite {
+            fovy: self.fovy,
+            aspect: self.aspect,
+            near: self.near,
+        }
+    }
+
+    #[inline]
+    pub fn reverse_z(self) -> ReverseZ<Perspective<S>> {
+        ReverseZ(self)
+    }
+
+    /// Equivalent off-axis frustum, see `Frustum`
+    pub fn to_frustum(&self) -> Frustum<S> {
+        let top = self.near * (self.fovy * S::HALF).tan();
+        let right = top * self.aspect;
+        Frustum {
+            left: -right,
+            right,
+            bottom: -top,
+            top,
+            near: self.near,
+            far: self.far,
+        }
+    }
+}
+
+impl<S: Float> PerspectiveInfinite<S> {
+    #[inline]
+    pub fn reverse_z(self) -> ReverseZ<PerspectiveInfinite<S>> {
+        ReverseZ(self)
+    }
+}
+
+macro_rules! impl_perspective_projection {
+    ($Proj:ty) => {
+        impl<S: Float> Projection<S> for $Proj {
+            fn to_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+                perspective_matrix(self, clip)
+            }
+
+            fn to_inverse_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+                perspective_inverse(self, clip)
+            }
+        }
+
+        /// Uses the `ClipSpace::DEFAULT` convention
+        impl<S: Float> From<$Proj> for Mat4<S> {
+            fn from(persp: $Proj) -> Mat4<S> {
+                persp.matrix()
+            }
+        }
+    };
+}
+
+impl_perspective_projection!(Perspective<S>);
+impl_perspective_projection!(PerspectiveInfinite<S>);
+impl_perspective_projection!(ReverseZ<Perspective<S>>);
+impl_perspective_projection!(ReverseZ<PerspectiveInfinite<S>>);
+
+/// Perspective projection with a possibly asymmetric (off-axis) view volume, equivalent to
+/// `glFrustum`. The left, right, bottom and top bounds are given at the near plane
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Frustum<S> {
+    pub left: S,
+    pub right: S,
+    pub bottom: S,
+    pub top: S,
+    pub near: S,
+    pub far: S,
+}
+
+/// Eye of a stereo pair
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Eye {
+    Left,
+    Right,
+}
+
+impl Eye {
+    /// Offset of the eye from the center of the stereo pair along the view space x axis
+    #[inline]
+    pub fn offset<S: Float>(self, ipd: S) -> S {
+        match self {
+            Eye::Left => -ipd * S::HALF,
+            Eye::Right => ipd * S::HALF,
+        }
+    }
+
+    /// Translation which, applied after the view matrix of the center of the stereo pair,
+    /// yields the view matrix of the eye
+    #[inline]
+    pub fn view_offset<S: Float>(self, ipd: S) -> Mat4<S> {
+        Mat4::from_translation(Vec3::new(-self.offset(ipd), S::ZERO, S::ZERO))
+    }
+}
+
+impl<S: Float> Frustum<S> {
+    /// Off-axis frustum of one eye of a stereo pair with parallel view directions. `ipd` is
+    /// the interpupillary distance and `convergence` the distance of the zero parallax plane,
+    /// where the view volumes of both eyes coincide with the view volume of `persp`.
+    /// Use together with `Eye::view_offset`
+    pub fn stereo_eye(persp: Perspective<S>, eye: Eye, ipd: S, convergence: S) -> Frustum<S> {
+        let center = persp.to_frustum();
+        let shift = eye.offset(ipd) * persp.near / convergence;
+        Frustum {
+            left: center.left - shift,
+            right: center.right - shift,
+            ..center
+        }
+    }
+}
+
+impl<S: Float> Projection<S> for Frustum<S> {
     #[rustfmt::skip]
-    fn from(persp: Perspective<S>) -> Mat4<S> {
-        let half_fov = persp.fovy * S::HALF;
-        let f = half_fov.cos() / half_fov.sin();
-        let d = persp.far - persp.near;
-
-        Mat4::new(
-            f / persp.aspect, S::ZERO, S::ZERO, S::ZERO,
-            S::ZERO, -f, S::ZERO, S::ZERO,
-            S::ZERO, S::ZERO, -persp.far / d, -S::ONE,
-            S::ZERO, S::ZERO, -(persp.far * persp.near) / d, S::ZERO,
-        )
+    fn to_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+        let width = self.right - self.left;
+        let height = self.top - self.bottom;
+        let two_near = self.near + self.near;
+        let (a, b) = finite_depth(self.near, self.far, clip.near_depth());
+
+        clip.apply(Mat4::new(
+            two_near / width, S::ZERO, S::ZERO, S::ZERO,
+            S::ZERO, two_near / height, S::ZERO, S::ZERO,
+            (self.right + self.left) / width, (self.top + self.bottom) / height, a, -S::ONE,
+            S::ZERO, S::ZERO, b, S::ZERO,
+        ))
+    }
+
+    #[rustfmt::skip]
+    fn to_inverse_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+        let width = self.right - self.left;
+        let height = self.top - self.bottom;
+        let two_near = self.near + self.near;
+        let (a, b) = finite_depth(self.near, self.far, clip.near_depth());
+
+        clip.apply_inverse(Mat4::new(
+            width / two_near, S::ZERO, S::ZERO, S::ZERO,
+            S::ZERO, height / two_near, S::ZERO, S::ZERO,
+            S::ZERO, S::ZERO, S::ZERO, S::ONE / b,
+            (self.right + self.left) / two_near, (self.top + self.bottom) / two_near, -S::ONE, a / b,
+        ))
+    }
+}
+
+impl<S: Float> From<Perspective<S>> for Frustum<S> {
+    #[inline]
+    fn from(persp: Perspective<S>) -> Frustum<S> {
+        persp.to_frustum()
+    }
+}
+
+/// Uses the `ClipSpace::DEFAULT` convention, so a symmetric frustum gives the same matrix as
+/// the `Perspective` it was made from. `glFrustum` corresponds to `ClipSpace::OPENGL`
+impl<S: Float> From<Frustum<S>> for Mat4<S> {
+    fn from(frustum: Frustum<S>) -> Mat4<S> {
+        frustum.matrix()
     }
 }
 
@@ -34,19 +479,980 @@ pub struct Ortho<S> {
     pub far: S,
 }
 
-impl<S: Float> From<Ortho<S>> for Mat4<S> {
+impl<S: Float> Projection<S> for Ortho<S> {
     #[rustfmt::skip]
-    fn from(ortho: Ortho<S>) -> Mat4<S> {
-        let x = ortho.right - ortho.left;
-        let y = ortho.top - ortho.bottom;
-        let z = ortho.far - ortho.near;
+    fn to_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+        let x = self.right - self.left;
+        let y = self.top - self.bottom;
+        let z = self.far - self.near;
         let two = S::ONE + S::ONE;
+        let near_depth: S = clip.near_depth();
+        let c = -(S::ONE - near_depth) / z;
 
-        Mat4::new(
+        clip.apply(Mat4::new(
             two / x, S::ZERO, S::ZERO, S::ZERO,
             S::ZERO, two / y, S::ZERO, S::ZERO,
-            S::ZERO, S::ZERO, -two / z, S::ZERO,
-            (ortho.left + ortho.right) / -x, (ortho.bottom + ortho.top) / -y, (ortho.near + ortho.far) / -z, S::ONE,
+            S::ZERO, S::ZERO, c, S::ZERO,
+            (self.left + self.right) / -x, (self.bottom + self.top) / -y, near_depth + c * self.near, S::ONE,
+        ))
+    }
+
+    #[rustfmt::skip]
+    fn to_inverse_mat4(&self, clip: ClipSpace) -> Mat4<S> {
+        let x = self.right - self.left;
+        let y = self.top - self.bottom;
+        let z = self.far - self.near;
+        let near_depth: S = clip.near_depth();
+        let c = -(S::ONE - near_depth) / z;
+
+        clip.apply_inverse(Mat4::new(
+            x * S::HALF, S::ZERO, S::ZERO, S::ZERO,
+            S::ZERO, y * S::HALF, S::ZERO, S::ZERO,
+            S::ZERO, S::ZERO, S::ONE / c, S::ZERO,
+            (self.left + self.right) * S::HALF, (self.bottom + self.top) * S::HALF, -(near_depth / c + self.near), S::ONE,
+        ))
+    }
+}
+
+/// Uses the `ClipSpace::DEFAULT` convention, like the perspective projections
+impl<S: Float> From<Ortho<S>> for Mat4<S> {
+    fn from(ortho: Ortho<S>) -> Mat4<S> {
+        ortho.matrix()
+    }
+}
+
+/// Reason why projection parameters could not be recovered from a matrix
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ProjectionError {
+    /// The matrix does not have the structure of a perspective projection
+    NotPerspective,
+    /// The matrix does not have the structure of an orthographic projection
+    NotOrthographic,
+    /// The perspective projection has an asymmetric view volume, see `Frustum`
+    OffAxis,
+    /// The far plane is at infinity, see `PerspectiveInfinite`
+    InfiniteFarPlane,
+    /// The far plane is not at infinity, see `Perspective`
+    FiniteFarPlane,
+    /// The near and far planes are not in front of the viewer or are in the wrong order
+    InvalidDepth,
+}
+
+impl std::fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ProjectionError::NotPerspective => "matrix is not a perspective projection",
+            ProjectionError::NotOrthographic => "matrix is not an orthographic projection",
+            ProjectionError::OffAxis => "perspective projection is off-axis",
+            ProjectionError::InfiniteFarPlane => "perspective projection has an infinite far plane",
+            ProjectionError::FiniteFarPlane => "perspective projection has a finite far plane",
+            ProjectionError::InvalidDepth => "projection has invalid near and far planes",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ProjectionError {}
+
+/// Projection parameters recovered from a matrix together with the convention it was built for.
+///
+/// The depth range cannot be told from a projection matrix alone, since any matrix can be
+/// read with either range. Unless given explicitly, it is inferred from the other properties:
+/// a flipped y axis (Vulkan) or a left-handed view (Direct3D) imply `[0, 1]`, otherwise the
+/// OpenGL range `[-1, 1]` is assumed, which is also the convention of glTF
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Detected<P> {
+    pub projection: P,
+    pub clip: ClipSpace,
+    /// Near plane mapped to depth 1, see `ReverseZ`
+    pub reverse_z: bool,
+}
+
+/// See `Detected`
+fn infer_depth(handedness: Handedness, flip_y: bool) -> DepthRange {
+    if flip_y || handedness == Handedness::Left {
+        DepthRange::ZeroToOne
+    } else {
+        DepthRange::NegativeOneToOne
+    }
+}
+
+/// Whether `value` vanishes next to `reference`, the element of the matrix it is compared with,
+/// up to rounding noise of the matrix elements
+fn is_negligible<S: Float>(value: S, reference: S) -> bool {
+    value.abs() <= S::EPSILON.sqrt() * reference.abs()
+}
+
+/// Shared parameters of perspective matrices: the view space z to clip z coefficients `a`, `b`
+/// brought to the right-handed form, the scale factors and the detected handedness and y flip
+struct PerspectiveParts<S> {
+    sx: S,
+    sy: S,
+    a: S,
+    b: S,
+    handedness: Handedness,
+    flip_y: bool,
+}
+
+impl<S: Float> PerspectiveParts<S> {
+    fn new(m: &Mat4<S>) -> Result<PerspectiveParts<S>, ProjectionError> {
+        let w = m.z.w;
+        if w == S::ZERO || !is_negligible(m.w.w, w) {
+            return Err(ProjectionError::NotPerspective);
+        }
+        if !is_negligible(m.x.y, m.x.x) || !is_negligible(m.x.z, m.x.x) {
+            return Err(ProjectionError::NotPerspective);
+        }
+        if !is_negligible(m.y.x, m.y.y) || !is_negligible(m.y.z, m.y.y) {
+            return Err(ProjectionError::NotPerspective);
+        }
+        if !is_negligible(m.x.w, w) || !is_negligible(m.y.w, w) {
+            return Err(ProjectionError::NotPerspective);
+        }
+        if !is_negligible(m.w.x, m.w.z) || !is_negligible(m.w.y, m.w.z) {
+            return Err(ProjectionError::NotPerspective);
+        }
+        if !is_negligible(m.z.x, w) || !is_negligible(m.z.y, w) {
+            return Err(ProjectionError::OffAxis);
+        }
+        // Projection matrices are defined up to scale, normalize so that w = -z_view. Dividing
+        // keeps the coefficients of matrices built with w = -1 exact
+        let scale = w.abs();
+        let handedness = if w < S::ZERO {
+            Handedness::Right
+        } else {
+            Handedness::Left
+        };
+        let a = match handedness {
+            Handedness::Right => m.z.z / scale,
+            Handedness::Left => -m.z.z / scale,
+        };
+        let (sx, sy) = (m.x.x / scale, m.y.y / scale);
+        if sx <= S::ZERO || sy == S::ZERO {
+            return Err(ProjectionError::NotPerspective);
+        }
+        Ok(PerspectiveParts {
+            sx,
+            sy: sy.abs(),
+            a,
+            b: m.w.z / scale,
+            handedness,
+            flip_y: sy < S::ZERO,
+        })
+    }
+
+    fn clip(&self, depth: DepthRange) -> ClipSpace {
+        ClipSpace {
+            handedness: self.handedness,
+            depth,
+            flip_y: self.flip_y,
+        }
+    }
+
+    fn fovy(&self) -> Rad<S> {
+        Rad::atan(self.sy.recip()) * (S::ONE + S::ONE)
+    }
+
+    /// Depth of the near plane, depth of the far plane in normalized device coordinates and
+    /// whether the depth is reversed. In the right-handed form, the normalized depth of a point
+    /// at distance `d` is `b / d - a`, which decreases with distance when `b` is positive
+    fn depth_ends(&self, clip: ClipSpace) -> (S, S, bool) {
+        let low: S = clip.near_depth();
+        if self.b > S::ZERO {
+            (S::ONE, low, true)
+        } else {
+            (low, S::ONE, false)
+        }
+    }
+
+    /// Distance at which the normalized depth equals `depth`, `None` for infinity. With a
+    /// large far to near ratio the denominator is a few units in the last place of `a`, so
+    /// only a remainder below the rounding of `a` counts as an infinite far plane
+    fn distance(&self, depth: S) -> Option<S> {
+        let denom = depth + self.a;
+        let quarter = S::HALF * S::HALF;
+        if denom.abs() <= S::EPSILON * quarter * self.a.abs().max(depth.abs()) {
+            None
+        } else {
+            Some(self.b / denom)
+        }
+    }
+}
+
+impl<S: Float> Perspective<S> {
+    /// Recover the parameters and the convention of a finite perspective projection matrix,
+    /// inferring the depth range as described for `Detected`
+    pub fn detect(m: &Mat4<S>) -> Result<Detected<Perspective<S>>, ProjectionError> {
+        let parts = PerspectiveParts::new(m)?;
+        Self::detect_parts(
+            parts.clip(infer_depth(parts.handedness, parts.flip_y)),
+            &parts,
         )
     }
+
+    /// Recover the parameters and the convention of a finite perspective projection matrix
+    /// built for the given depth range
+    pub fn detect_with_depth(
+        m: &Mat4<S>,
+        depth: DepthRange,
+    ) -> Result<Detected<Perspective<S>>, ProjectionError> {
+        let parts = PerspectiveParts::new(m)?;
+        Self::detect_parts(parts.clip(depth), &parts)
+    }
+
+    fn detect_parts(
+        clip: ClipSpace,
+        parts: &PerspectiveParts<S>,
+    ) -> Result<Detected<Perspective<S>>, ProjectionError> {
+        let (near_depth, far_depth, reverse_z) = parts.depth_ends(clip);
+        let near = parts
+            .distance(near_depth)
+            .ok_or(ProjectionError::InvalidDepth)?;
+        let far = parts
+            .distance(far_depth)
+            .ok_or(ProjectionError::InfiniteFarPlane)?;
+        if near <= S::ZERO || far <= near {
+            return Err(ProjectionError::InvalidDepth);
+        }
+        let projection = Perspective {
+            fovy: parts.fovy(),
+            aspect: parts.sy / parts.sx,
+            near,
+            far,
+        };
+        Ok(Detected {
+            projection,
+            clip,
+            reverse_z,
+        })
+    }
+}
+
+impl<S: Float> PerspectiveInfinite<S> {
+    /// Recover the parameters and the convention of an infinite perspective projection matrix,
+    /// inferring the depth range as described for `Detected`
+    pub fn detect(m: &Mat4<S>) -> Result<Detected<PerspectiveInfinite<S>>, ProjectionError> {
+        let parts = PerspectiveParts::new(m)?;
+        Self::detect_parts(
+            parts.clip(infer_depth(parts.handedness, parts.flip_y)),
+            &parts,
+        )
+    }
+
+    /// Recover the parameters and the convention of an infinite perspective projection matrix
+    /// built for the given depth range
+    pub fn detect_with_depth(
+        m: &Mat4<S>,
+        depth: DepthRange,
+    ) -> Result<Detected<PerspectiveInfinite<S>>, ProjectionError> {
+        let parts = PerspectiveParts::new(m)?;
+        Self::detect_parts(parts.clip(depth), &parts)
+    }
+
+    fn detect_parts(
+        clip: ClipSpace,
+        parts: &PerspectiveParts<S>,
+    ) -> Result<Detected<PerspectiveInfinite<S>>, ProjectionError> {
+        let (near_depth, far_depth, reverse_z) = parts.depth_ends(clip);
+        let near = parts
+            .distance(near_depth)
+            .ok_or(ProjectionError::InvalidDepth)?;
+        if parts.distance(far_depth).is_some() {
+            return Err(ProjectionError::FiniteFarPlane);
+        }
+        if near <= S::ZERO {
+            return Err(ProjectionError::InvalidDepth);
+        }
+        let projection = PerspectiveInfinite {
+            fovy: parts.fovy(),
+            aspect: parts.sy / parts.sx,
+            near,
+        };
+        Ok(Detected {
+            projection,
+            clip,
+            reverse_z,
+        })
+    }
+}
+
+impl<S: Float> Ortho<S> {
+    /// Recover the parameters and the convention of an orthographic projection matrix,
+    /// inferring the depth range as described for `Detected`. Reversed depth is
+    /// indistinguishable from a change of handedness and is never reported
+    pub fn detect(m: &Mat4<S>) -> Result<Detected<Ortho<S>>, ProjectionError> {
+        let handedness = if m.z.z < S::ZERO {
+            Handedness::Right
+        } else {
+            Handedness::Left
+        };
+        Self::detect_with_depth(m, infer_depth(handedness, m.y.y < S::ZERO))
+    }
+
+    /// Recover the parameters and the convention of an orthographic projection matrix built
+    /// for the given depth range
+    pub fn detect_with_depth(
+        m: &Mat4<S>,
+        depth: DepthRange,
+    ) -> Result<Detected<Ortho<S>>, ProjectionError> {
+        let scale = m.w.w;
+        if scale == S::ZERO {
+            return Err(ProjectionError::NotOrthographic);
+        }
+        if !is_negligible(m.x.w, scale)
+            || !is_negligible(m.y.w, scale)
+            || !is_negligible(m.z.w, scale)
+        {
+            return Err(ProjectionError::NotOrthographic);
+        }
+        if !is_negligible(m.x.y, m.x.x) || !is_negligible(m.x.z, m.x.x) {
+            return Err(ProjectionError::NotOrthographic);
+        }
+        if !is_negligible(m.y.x, m.y.y) || !is_negligible(m.y.z, m.y.y) {
+            return Err(ProjectionError::NotOrthographic);
+        }
+        if m.z.z == S::ZERO || !is_negligible(m.z.x, m.z.z) || !is_negligible(m.z.y, m.z.z) {
+            return Err(ProjectionError::NotOrthographic);
+        }
+        let m = *m / scale;
+        if m.x.x <= S::ZERO || m.y.y == S::ZERO {
+            return Err(ProjectionError::NotOrthographic);
+        }
+
+        let clip = ClipSpace {
+            handedness: if m.z.z < S::ZERO {
+                Handedness::Right
+            } else {
+                Handedness::Left
+            },
+            depth,
+            flip_y: m.y.y < S::ZERO,
+        };
+        let (sy, ty) = if clip.flip_y {
+            (-m.y.y, -m.w.y)
+        } else {
+            (m.y.y, m.w.y)
+        };
+        let c = -m.z.z.abs();
+        let low: S = clip.near_depth();
+        let near = (m.w.z - low) / c;
+        let far = near - (S::ONE - low) / c;
+        if near >= far {
+            return Err(ProjectionError::InvalidDepth);
+        }
+
+        let projection = Ortho {
+            left: (-m.w.x - S::ONE) / m.x.x,
+            right: (S::ONE - m.w.x) / m.x.x,
+            bottom: (-ty - S::ONE) / sy,
+            top: (S::ONE - ty) / sy,
+            near,
+            far,
+        };
+        Ok(Detected {
+            projection,
+            clip,
+            reverse_z: false,
+        })
+    }
+}
+
+/// See `Perspective::detect`
+impl<S: Float> TryFrom<Mat4<S>> for Perspective<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Mat4<S>) -> Result<Perspective<S>, ProjectionError> {
+        Perspective::detect(&m).map(|detected| detected.projection)
+    }
+}
+
+/// See `PerspectiveInfinite::detect`
+impl<S: Float> TryFrom<Mat4<S>> for PerspectiveInfinite<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Mat4<S>) -> Result<PerspectiveInfinite<S>, ProjectionError> {
+        PerspectiveInfinite::detect(&m).map(|detected| detected.projection)
+    }
+}
+
+/// See `Ortho::detect`
+impl<S: Float> TryFrom<Mat4<S>> for Ortho<S> {
+    type Error = ProjectionError;
+
+    fn try_from(m: Mat4<S>) -> Result<Ortho<S>, ProjectionError> {
+        Ortho::detect(&m).map(|detected| detected.projection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ClipSpace, Deg, DepthRange, Eye, Frustum, Handedness, InnerSpace, Mat4, Ortho, Perspective,
+        PerspectiveInfinite, Projection, ProjectionError, SquareMatrix, Vec2, Vec3, Vec4, Zero,
+    };
+
+    const CONVENTIONS: [ClipSpace; 8] = [
+        ClipSpace::OPENGL,
+        ClipSpace::DIRECT3D,
+        ClipSpace::WEBGPU,
+        ClipSpace::VULKAN,
+        ClipSpace::OPENGL.with_handedness(Handedness::Left),
+        ClipSpace::DIRECT3D.with_handedness(Handedness::Right),
+        ClipSpace::WEBGPU.with_handedness(Handedness::Left),
+        ClipSpace::VULKAN.with_handedness(Handedness::Left),
+    ];
+
+    const PERSP: Perspective<f64> = Perspective {
+        fovy: crate::Rad(std::f64::consts::FRAC_PI_2),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+
+    fn depth(m: Mat4<f64>, z: f64) -> f64 {
+        m.transform_point3(Vec3::new(0.0, 0.0, z)).z
+    }
+
+    #[test]
+    fn test_perspective_depth() {
+        let m = Mat4::from(PERSP);
+        assert_approx_eq!(depth(m, -0.1), 0.0, 1e-12);
+        assert_approx_eq!(depth(m, -100.0), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_infinite_depth() {
+        let m = Mat4::from(PERSP.infinite());
+        assert_approx_eq!(depth(m, -0.1), 0.0, 1e-12);
+        assert_approx_eq!(depth(m, -1e12), 1.0, 1e-12);
+        assert!(depth(m, -1e12) < 1.0);
+        // A point at infinity is a direction with w = 0
+        let inf = m * Vec3::new(0.0, 0.0, -1.0).extend(0.0);
+        assert_approx_eq!(inf.z / inf.w, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_reverse_z_depth() {
+        let m = Mat4::from(PERSP.reverse_z());
+        assert_approx_eq!(depth(m, -0.1), 1.0, 1e-12);
+        assert_approx_eq!(depth(m, -100.0), 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_infinite_reverse_z_depth() {
+        let m = Mat4::from(PERSP.infinite().reverse_z());
+        assert_approx_eq!(depth(m, -0.1), 1.0, 1e-12);
+        assert_approx_eq!(depth(m, -1e12), 0.0, 1e-12);
+        assert!(depth(m, -1e12) > 0.0);
+        let inf = m * Vec3::new(0.0, 0.0, -1.0).extend(0.0);
+        assert_approx_eq!(inf.z / inf.w, 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_matrix() {
+        let persp = Perspective {
+            fovy: Deg(60.0).into(),
+            ..PERSP
+        };
+        let pairs = [
+            (Mat4::from(persp), persp.inverse_matrix()),
+            (
+                Mat4::from(persp.infinite()),
+                persp.infinite().inverse_matrix(),
+            ),
+            (
+                Mat4::from(persp.reverse_z()),
+                persp.reverse_z().inverse_matrix(),
+            ),
+            (
+                Mat4::from(persp.infinite().reverse_z()),
+                persp.infinite().reverse_z().inverse_matrix(),
+            ),
+        ];
+        for (m, inv) in pairs {
+            assert_approx_eq!(m * inv, Mat4::IDENTITY, 1e-9);
+            assert_approx_eq!(inv, m.inverse().unwrap(), 1e-9);
+        }
+    }
+
+    /// Corners of the view volume in view space and where they should land in NDC,
+    /// given the distance of the near and far plane along the view direction
+    fn expected_corners(
+        clip: ClipSpace,
+        near: (f64, f64, f64),
+        far: (f64, f64, f64),
+    ) -> Vec<(Vec3<f64>, Vec3<f64>)> {
+        let forward = match clip.handedness {
+            Handedness::Right => -1.0,
+            Handedness::Left => 1.0,
+        };
+        let up = if clip.flip_y { -1.0 } else { 1.0 };
+        let near_depth = match clip.depth {
+            DepthRange::NegativeOneToOne => -1.0,
+            DepthRange::ZeroToOne => 0.0,
+        };
+        let mut corners = Vec::new();
+        for (&(w, h, d), depth) in [(&near, near_depth), (&far, 1.0)] {
+            for (sx, sy) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+                corners.push((
+                    Vec3::new(sx * w, sy * h, forward * d),
+                    Vec3::new(sx, up * sy, depth),
+                ));
+            }
+        }
+        corners
+    }
+
+    #[test]
+    fn test_perspective_conventions() {
+        let tan = (PERSP.fovy.0 * 0.5).tan();
+        for clip in CONVENTIONS {
+            let m = PERSP.to_mat4(clip);
+            let near = (
+                PERSP.near * tan * PERSP.aspect,
+                PERSP.near * tan,
+                PERSP.near,
+            );
+            let far = (PERSP.far * tan * PERSP.aspect, PERSP.far * tan, PERSP.far);
+            for (view, ndc) in expected_corners(clip, near, far) {
+                assert_approx_eq!(m.transform_point3(view), ndc, 1e-9);
+            }
+            assert_approx_eq!(m * PERSP.to_inverse_mat4(clip), Mat4::IDENTITY, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_ortho_conventions() {
+        let ortho = Ortho {
+            left: -3.0,
+            right: 5.0,
+            bottom: -1.0,
+            top: 2.0,
+            near: 0.5,
+            far: 20.0,
+        };
+        for clip in CONVENTIONS {
+            let m = ortho.to_mat4(clip);
+            for (view, ndc) in expected_corners(clip, (1.0, 1.0, 0.5), (1.0, 1.0, 20.0)) {
+                // Shift the symmetric corners to the asymmetric ortho box
+                let view = Vec3::new(
+                    if view.x < 0.0 {
+                        ortho.left
+                    } else {
+                        ortho.right
+                    },
+                    if view.y < 0.0 {
+                        ortho.bottom
+                    } else {
+                        ortho.top
+                    },
+                    view.z,
+                );
+                assert_approx_eq!(m.transform_point3(view), ndc, 1e-12);
+            }
+            assert_approx_eq!(m * ortho.to_inverse_mat4(clip), Mat4::IDENTITY, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_reverse_z_conventions() {
+        for clip in CONVENTIONS {
+            let m = PERSP.reverse_z().to_mat4(clip);
+            let forward = match clip.handedness {
+                Handedness::Right => -1.0,
+                Handedness::Left => 1.0,
+            };
+            let near_depth = m
+                .transform_point3(Vec3::new(0.0, 0.0, forward * PERSP.near))
+                .z;
+            let far_depth = m
+                .transform_point3(Vec3::new(0.0, 0.0, forward * PERSP.far))
+                .z;
+            assert_approx_eq!(near_depth, 1.0, 1e-9);
+            let expected_far = match clip.depth {
+                DepthRange::NegativeOneToOne => -1.0,
+                DepthRange::ZeroToOne => 0.0,
+            };
+            assert_approx_eq!(far_depth, expected_far, 1e-9);
+
+            let inf = PERSP.infinite().to_mat4(clip) * Vec4::new(0.0, 0.0, forward, 0.0);
+            assert_approx_eq!(inf.z / inf.w, 1.0, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_default_conventions() {
+        assert_eq!(ClipSpace::DEFAULT, ClipSpace::VULKAN);
+        assert_eq!(Mat4::from(PERSP), PERSP.to_mat4(ClipSpace::DEFAULT));
+        let ortho = Ortho {
+            left: -1.0,
+            right: 1.0,
+            bottom: -1.0,
+            top: 1.0,
+            near: 0.5,
+            far: 10.0,
+        };
+        assert_eq!(Mat4::from(ortho), ortho.to_mat4(ClipSpace::DEFAULT));
+        // Perspective and orthographic matrices agree on the y direction and depth range
+        let top_near = Vec3::new(0.0, 1.0, -0.5);
+        assert_approx_eq!(
+            Mat4::from(ortho).transform_point3(top_near),
+            Vec3::new(0.0, -1.0, 0.0),
+            1e-12
+        );
+        let tan = (PERSP.fovy.0 * 0.5).tan();
+        let top_near = Vec3::new(0.0, PERSP.near * tan, -PERSP.near);
+        assert_approx_eq!(
+            Mat4::from(PERSP).transform_point3(top_near),
+            Vec3::new(0.0, -1.0, 0.0),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_frustum() {
+        let frustum = Frustum {
+            left: -0.3,
+            right: 0.5,
+            bottom: -0.2,
+            top: 0.4,
+            near: 0.5,
+            far: 50.0,
+        };
+        #[rustfmt::skip]
+        let gl = Mat4::new(
+            1.0 / 0.8, 0.0, 0.0, 0.0,
+            0.0, 1.0 / 0.6, 0.0, 0.0,
+            0.2 / 0.8, 0.2 / 0.6, -50.5 / 49.5, -1.0,
+            0.0, 0.0, -50.0 / 49.5, 0.0,
+        );
+        assert_approx_eq!(frustum.to_mat4(ClipSpace::OPENGL), gl, 1e-12);
+        assert_eq!(Mat4::from(frustum), frustum.to_mat4(ClipSpace::DEFAULT));
+        for clip in CONVENTIONS {
+            let m = frustum.to_mat4(clip);
+            assert_approx_eq!(m * frustum.to_inverse_mat4(clip), Mat4::IDENTITY, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_frustum_from_perspective() {
+        let frustum = Frustum::from(PERSP);
+        assert_eq!(frustum, PERSP.to_frustum());
+        assert_approx_eq!(Mat4::from(frustum), Mat4::from(PERSP), 1e-12);
+        assert_approx_eq!(frustum.inverse_matrix(), PERSP.inverse_matrix(), 1e-12);
+        for clip in CONVENTIONS {
+            assert_approx_eq!(frustum.to_mat4(clip), PERSP.to_mat4(clip), 1e-12);
+            assert_approx_eq!(
+                frustum.to_inverse_mat4(clip),
+                PERSP.to_inverse_mat4(clip),
+                1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_stereo() {
+        let (ipd, convergence) = (0.064, 2.0);
+        let tan = (PERSP.fovy.0 * 0.5).tan();
+        let corner = Vec3::new(
+            convergence * tan * PERSP.aspect,
+            convergence * tan,
+            -convergence,
+        );
+        for eye in [Eye::Left, Eye::Right] {
+            let m = Mat4::from(Frustum::stereo_eye(PERSP, eye, ipd, convergence))
+                * eye.view_offset(ipd);
+            // The zero parallax plane is seen identically by both eyes
+            let center = m.transform_point3(Vec3::new(0.0, 0.0, -convergence));
+            assert_approx_eq!(center.x, 0.0, 1e-12);
+            assert_approx_eq!(m.transform_point3(corner).x, 1.0, 1e-12);
+            // The default convention flips y
+            assert_approx_eq!(m.transform_point3(corner).y, -1.0, 1e-12);
+        }
+        let left = Eye::Left
+            .view_offset(ipd)
+            .transform_point3(Vec3::new(-ipd * 0.5, 0.0, 0.0));
+        assert_approx_eq!(left, Vec3::new(0.0, 0.0, 0.0), 1e-12);
+    }
+
+    fn check_projection<P: Projection<f64> + Copy>(proj: P, near: f64, far: f64)
+    where
+        Mat4<f64>: From<P>,
+    {
+        let m = Mat4::from(proj);
+        assert_eq!(proj.matrix(), m);
+        let p = Vec3::new(0.3, -0.2, -1.5);
+        assert_eq!(proj.project(p), m.transform_point3(p));
+        assert_approx_eq!(proj.unproject(proj.project(p)), p, 1e-12);
+
+        let near_depth = proj.project(Vec3::new(0.0, 0.0, -near)).z;
+        assert_approx_eq!(proj.linearize_depth(near_depth), near, 1e-9);
+        let depth = proj.project(p).z;
+        assert_approx_eq!(proj.linearize_depth(depth), 1.5, 1e-9);
+        if far.is_finite() {
+            let far_depth = proj.project(Vec3::new(0.0, 0.0, -far)).z;
+            assert_approx_eq!(proj.linearize_depth(far_depth), far, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_projection() {
+        check_projection(PERSP, PERSP.near, PERSP.far);
+        check_projection(PERSP.infinite(), PERSP.near, f64::INFINITY);
+        check_projection(PERSP.reverse_z(), PERSP.near, PERSP.far);
+        check_projection(PERSP.infinite().reverse_z(), PERSP.near, f64::INFINITY);
+        check_projection(PERSP.to_frustum(), PERSP.near, PERSP.far);
+        let ortho = Ortho {
+            left: -3.0,
+            right: 5.0,
+            bottom: -1.0,
+            top: 2.0,
+            near: 0.5,
+            far: 20.0,
+        };
+        check_projection(ortho, ortho.near, ortho.far);
+    }
+
+    #[test]
+    fn test_project_world() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let view = Mat4::look_at(eye, Vec3::new(4.0, 0.0, -5.0), Vec3::Y);
+        let point = Vec3::new(2.0, 1.5, -4.0);
+        let ndc = PERSP.project_world(&view, point);
+        assert_eq!(ndc, PERSP.project(view.transform_point3(point)));
+        assert_approx_eq!((PERSP.matrix() * view).transform_point3(point), ndc, 1e-12);
+        assert_approx_eq!(PERSP.unproject_world(&view, ndc).unwrap(), point, 1e-9);
+        // The center of the screen on the near plane is right in front of the eye
+        let forward = (Vec3::new(4.0, 0.0, -5.0) - eye).normalize();
+        let near = PERSP
+            .unproject_world(&view, Vec3::new(0.0, 0.0, 0.0))
+            .unwrap();
+        assert_approx_eq!(near, eye + forward * PERSP.near, 1e-9);
+        assert_eq!(PERSP.unproject_world(&Mat4::from_scale(0.0), ndc), None);
+    }
+
+    #[test]
+    fn test_screen_ray() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let view = Mat4::look_at(eye, Vec3::new(1.0, 2.0, -7.0), Vec3::Y);
+        let viewport = Vec2::new(800.0, 600.0);
+        let (origin, dir) = PERSP
+            .screen_ray(&view, Vec2::new(400.0, 300.0), viewport)
+            .unwrap();
+        assert_approx_eq!(origin, eye - Vec3::new(0.0, 0.0, PERSP.near), 1e-9);
+        assert_approx_eq!(dir, Vec3::new(0.0, 0.0, -1.0), 1e-9);
+
+        // Top-left corner of the viewport, the Vulkan convention flips y
+        let (origin, dir) = PERSP
+            .reverse_z()
+            .screen_ray(&view, Vec2::ZERO, viewport)
+            .unwrap();
+        let tan = (PERSP.fovy.0 * 0.5).tan();
+        let expected = Vec3::new(-tan * PERSP.aspect, tan, -1.0);
+        assert_approx_eq!(origin, eye + expected * PERSP.near, 1e-9);
+        assert_approx_eq!(dir, expected.normalize(), 1e-9);
+
+        let ortho = Ortho {
+            left: -4.0,
+            right: 4.0,
+            bottom: -3.0,
+            top: 3.0,
+            near: 0.5,
+            far: 20.0,
+        };
+        let (origin, dir) = ortho
+            .screen_ray(&view, Vec2::new(0.0, 600.0), viewport)
+            .unwrap();
+        assert_approx_eq!(origin, eye + Vec3::new(-4.0, -3.0, -0.5), 1e-9);
+        assert_approx_eq!(dir, Vec3::new(0.0, 0.0, -1.0), 1e-9);
+    }
+
+    #[test]
+    fn test_detect_perspective() {
+        let persp = Perspective {
+            fovy: Deg(60.0).into(),
+            aspect: 1.5,
+            near: 0.1,
+            far: 100.0,
+        };
+        for clip in CONVENTIONS {
+            for (m, reverse_z) in [
+                (persp.to_mat4(clip), false),
+                (persp.reverse_z().to_mat4(clip), true),
+            ] {
+                let detected = Perspective::detect_with_depth(&m, clip.depth).unwrap();
+                assert_eq!(detected.clip, clip);
+                assert_eq!(detected.reverse_z, reverse_z);
+                assert_approx_eq!(detected.projection.fovy.0, persp.fovy.0, 1e-12);
+                assert_approx_eq!(detected.projection.aspect, persp.aspect, 1e-12);
+                assert_approx_eq!(detected.projection.near, persp.near, 1e-9);
+                assert_approx_eq!(detected.projection.far, persp.far, 1e-9);
+                assert_eq!(
+                    PerspectiveInfinite::detect_with_depth(&m, clip.depth),
+                    Err(ProjectionError::FiniteFarPlane)
+                );
+            }
+        }
+
+        let conventions = [ClipSpace::OPENGL, ClipSpace::VULKAN, ClipSpace::DIRECT3D];
+        for clip in conventions {
+            let detected = Perspective::detect(&persp.to_mat4(clip)).unwrap();
+            assert_eq!(detected.clip, clip);
+        }
+        let recovered = Perspective::try_from(Mat4::from(persp)).unwrap();
+        assert_approx_eq!(recovered.near, persp.near, 1e-9);
+        assert_approx_eq!(recovered.far, persp.far, 1e-9);
+    }
+
+    #[test]
+    fn test_detect_perspective_infinite() {
+        let persp = PERSP.infinite();
+        for clip in CONVENTIONS {
+            for (m, reverse_z) in [
+                (persp.to_mat4(clip), false),
+                (persp.reverse_z().to_mat4(clip), true),
+            ] {
+                let detected = PerspectiveInfinite::detect_with_depth(&m, clip.depth).unwrap();
+                assert_eq!(detected.clip, clip);
+                assert_eq!(detected.reverse_z, reverse_z);
+                assert_approx_eq!(detected.projection.near, persp.near, 1e-9);
+                assert_approx_eq!(detected.projection.aspect, persp.aspect, 1e-12);
+                assert_eq!(
+                    Perspective::detect_with_depth(&m, clip.depth),
+                    Err(ProjectionError::InfiniteFarPlane)
+                );
+            }
+        }
+        let m = Mat4::from(persp);
+        assert_approx_eq!(
+            PerspectiveInfinite::try_from(m).unwrap().near,
+            persp.near,
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_detect_ortho() {
+        let ortho = Ortho {
+            left: -3.0,
+            right: 5.0,
+            bottom: -1.0,
+            top: 2.0,
+            near: 0.5,
+            far: 20.0,
+        };
+        for clip in CONVENTIONS {
+            let detected = Ortho::detect_with_depth(&ortho.to_mat4(clip), clip.depth).unwrap();
+            assert_eq!(detected.clip, clip);
+            assert_approx_eq!(detected.projection.left, ortho.left, 1e-12);
+            assert_approx_eq!(detected.projection.right, ortho.right, 1e-12);
+            assert_approx_eq!(detected.projection.bottom, ortho.bottom, 1e-12);
+            assert_approx_eq!(detected.projection.top, ortho.top, 1e-12);
+            assert_approx_eq!(detected.projection.near, ortho.near, 1e-12);
+            assert_approx_eq!(detected.projection.far, ortho.far, 1e-12);
+        }
+        assert_eq!(
+            Ortho::detect(&Mat4::from(ortho)).unwrap().clip,
+            ClipSpace::VULKAN
+        );
+        assert_eq!(
+            Ortho::detect(&ortho.to_mat4(ClipSpace::OPENGL))
+                .unwrap()
+                .clip,
+            ClipSpace::OPENGL
+        );
+    }
+
+    #[test]
+    fn test_detect_precision() {
+        // In f32 the far plane of a 1e6 far to near ratio is a few units in the last place
+        // away from infinity, but still finite
+        for far in [1.0e3f32, 1.0e5, 1.0e6] {
+            let persp = Perspective {
+                fovy: Deg(60.0f32).into(),
+                aspect: 1.5,
+                near: 0.1,
+                far,
+            };
+            for clip in CONVENTIONS {
+                let detected = Perspective::detect_with_depth(&persp.to_mat4(clip), clip.depth)
+                    .unwrap()
+                    .projection;
+                assert_approx_eq!(detected.near, persp.near, 1e-4);
+                assert!((detected.far / far - 1.0).abs() < 0.2, "{far} {clip:?}");
+            }
+        }
+        for clip in CONVENTIONS {
+            let m = PerspectiveInfinite {
+                fovy: Deg(60.0f32).into(),
+                aspect: 1.5,
+                near: 0.1,
+            }
+            .to_mat4(clip);
+            assert_eq!(
+                Perspective::detect_with_depth(&m, clip.depth),
+                Err(ProjectionError::InfiniteFarPlane)
+            );
+            // Matrices are defined up to scale
+            let scaled = PerspectiveInfinite::detect_with_depth(&(m * 3.0), clip.depth);
+            assert_approx_eq!(scaled.unwrap().projection.near, 0.1, 1e-6);
+        }
+
+        // Noise from composing matrices in single precision is tolerated
+        let mut m = Mat4::from(PERSP);
+        m.x.y = 1e-12;
+        m.y.x = -1e-12;
+        m.w.w = 1e-14;
+        let detected = Perspective::try_from(m).unwrap();
+        assert_approx_eq!(detected.far, PERSP.far, 1e-6);
+        let ortho = Ortho {
+            left: -3.0f32,
+            right: 5.0,
+            bottom: -1.0,
+            top: 2.0,
+            near: 0.5,
+            far: 20.0,
+        };
+        let mut m = Mat4::from(ortho);
+        m.x.z = 1e-6;
+        m.z.w = -1e-6;
+        assert_approx_eq!(Ortho::try_from(m).unwrap().far, ortho.far, 1e-3);
+        m.z.x = 0.1;
+        assert_eq!(Ortho::try_from(m), Err(ProjectionError::NotOrthographic));
+    }
+
+    #[test]
+    fn test_detect_errors() {
+        let frustum = Frustum {
+            left: -0.3,
+            right: 0.5,
+            bottom: -0.2,
+            top: 0.4,
+            near: 0.5,
+            far: 50.0,
+        };
+        assert_eq!(
+            Perspective::try_from(Mat4::from(frustum)),
+            Err(ProjectionError::OffAxis)
+        );
+        assert_eq!(
+            Perspective::<f64>::try_from(Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0))),
+            Err(ProjectionError::NotPerspective)
+        );
+        assert_eq!(
+            Ortho::try_from(Mat4::from(PERSP)),
+            Err(ProjectionError::NotOrthographic)
+        );
+        assert_eq!(
+            Perspective::<f64>::try_from(Mat4::from(Ortho {
+                left: -1.0,
+                right: 1.0,
+                bottom: -1.0,
+                top: 1.0,
+                near: 0.0,
+                far: 1.0,
+            })),
+            Err(ProjectionError::NotPerspective)
+        );
+    }
 }
@@ -3,7 +3,8 @@ use std::ops::{
 };
 
 use crate::{
-    Angle, Euler, Float, InnerSpace, Mat3, Mat4, MetricSpace, One, Rad, Vec3, VectorSpace, Zero,
+    Angle, Error, Euler, Float, InnerSpace, Mat3, Mat4, Matrix, MetricSpace, One, Rad,
+    SquareMatrix, Vec3, VectorSpace, Zero,
 };
 
 #[repr(C)]
@@ -36,6 +37,153 @@ impl<S: Float> Quat<S> {
         self.conjugate() / self.magnitude2()
     }
 
+    /// Rotation by `angle` about the unit vector `axis`
+    #[inline]
+    pub fn from_axis_angle<A: Into<Rad<S>>>(axis: Vec3<S>, angle: A) -> Quat<S> {
+        let (s, c) = Rad::sin_cos(angle.into() * S::HALF);
+        Quat::from_sv(c, axis * s)
+    }
+
+    #[inline]
+    pub fn from_rotation_x<A: Into<Rad<S>>>(angle: A) -> Quat<S> {
+        Quat::from_axis_angle(Vec3::X, angle)
+    }
+
+    #[inline]
+    pub fn from_rotation_y<A: Into<Rad<S>>>(angle: A) -> Quat<S> {
+        Quat::from_axis_angle(Vec3::Y, angle)
+    }
+
+    #[inline]
+    pub fn from_rotation_z<A: Into<Rad<S>>>(angle: A) -> Quat<S> {
+        Quat::from_axis_angle(Vec3::Z, angle)
+    }
+
+    /// Unit axis and angle in the range [0, 2π] of a unit quaternion. The identity rotation
+    /// returns the x axis and a zero angle
+    pub fn to_axis_angle(self) -> (Vec3<S>, Rad<S>) {
+        let sin = self.v.magnitude();
+        let angle = Rad::atan2(sin, self.s) * (S::ONE + S::ONE);
+        if sin > S::ZERO {
+            (self.v / sin, angle)
+        } else {
+            (Vec3::X, angle)
+        }
+    }
+
+    /// Quaternion exponential
+    pub fn exp(self) -> Quat<S> {
+        let angle = self.v.magnitude();
+        let scale = self.s.exp();
+        if angle > S::ZERO {
+            let (s, c) = angle.sin_cos();
+            Quat::from_sv(c * scale, self.v * (s * scale / angle))
+        } else {
+            Quat::from_sv(scale, self.v * scale)
+        }
+    }
+
+    /// Quaternion logarithm, the inverse of `exp`. For a unit quaternion the result is the
+    /// half rotation vector, with zero scalar part. Negative real quaternions such as `-1` have
+    /// a logarithm of angle π about any axis, the x axis is used. The zero quaternion has no
+    /// logarithm, its scalar part is negative infinity so that `exp` gives zero back
+    pub fn ln(self) -> Quat<S> {
+        let sin = self.v.magnitude();
+        let magnitude = self.magnitude();
+        if sin > S::ZERO {
+            let angle = sin.atan2(self.s);
+            Quat::from_sv(magnitude.ln(), self.v * (angle / sin))
+        } else if self.s < S::ZERO {
+            Quat::from_sv(magnitude.ln(), Vec3::X * S::RAD_HALF_TURN)
+        } else {
+            Quat::from_sv(magnitude.ln(), Vec3::new(S::ZERO, S::ZERO, S::ZERO))
+        }
+    }
+
+    /// Raise a unit quaternion to the power `t`, scaling the rotation angle by `t`
+    #[inline]
+    pub fn powf(self, t: S) -> Quat<S> {
+        (self.ln() * t).exp()
+    }
+
+    /// Angle of the shortest rotation between two unit quaternions, in the range [0, π]
+    pub fn angle_between(self, other: Quat<S>) -> Rad<S> {
+        let diff = self.conjugate() * other;
+        Rad::atan2(diff.v.magnitude(), diff.s.abs()) * (S::ONE + S::ONE)
+    }
+
+    /// Rotate towards `target` by at most `max_angle`, along the shortest path
+    pub fn rotate_towards<A: Into<Rad<S>>>(self, target: Quat<S>, max_angle: A) -> Quat<S> {
+        let max_angle = max_angle.into();
+        let angle = self.angle_between(target);
+        if angle <= max_angle {
+            target
+        } else if max_angle <= Rad(S::ZERO) {
+            self
+        } else {
+            self.slerp(target, max_angle / angle)
+        }
+    }
+
+    /// Intermediate control point of the unit quaternion `self` for SQUAD interpolation,
+    /// given its neighbours in a sequence of rotations
+    pub fn squad_intermediate(self, prev: Quat<S>, next: Quat<S>) -> Quat<S> {
+        // Keep the neighbours in the same hemisphere so the spline takes the shortest path
+        let prev = if self.dot(prev) < S::ZERO {
+            -prev
+        } else {
+            prev
+        };
+        let next = if self.dot(next) < S::ZERO {
+            -next
+        } else {
+            next
+        };
+        let inv = self.conjugate();
+        let quarter = S::HALF * S::HALF;
+        let tangent = ((inv * next).ln() + (inv * prev).ln()) * -quarter;
+        (self * tangent.exp()).normalize()
+    }
+
+    /// Spherical quadrangle interpolation from `self` to `other` by `amount`, using the
+    /// intermediate control points `a` of `self` and `b` of `other` computed with
+    /// `squad_intermediate`. Gives a smooth spline through a sequence of rotations
+    pub fn squad(self, other: Quat<S>, a: Quat<S>, b: Quat<S>, amount: S) -> Quat<S> {
+        let t = (S::ONE + S::ONE) * amount * (S::ONE - amount);
+        self.slerp(other, amount).slerp(a.slerp(b, amount), t)
+    }
+
+    /// Convert a rotation matrix to a quaternion, checking that the matrix is an orthonormal
+    /// rotation within `tolerance`, see `Mat3::is_orthonormal`
+    pub fn try_from_rotation(mat: Mat3<S>, tolerance: S) -> Result<Quat<S>, Error> {
+        if !mat.is_finite() {
+            Err(Error::NonFinite)
+        } else if !mat.is_orthonormal(tolerance) || mat.determinant() <= S::ZERO {
+            Err(Error::NotOrthonormal)
+        } else {
+            Ok(Quat::from(mat).normalize())
+        }
+    }
+
+    /// Orientation turning the negative z axis towards `forward` and the y axis towards `up`,
+    /// the inverse of the view rotation `Mat3::look_to`. When `up` is parallel to `forward`
+    /// another up vector is used
+    pub fn look_rotation(forward: Vec3<S>, up: Vec3<S>) -> Quat<S> {
+        Quat::from(Mat3::look_to(forward, up).transpose()).normalize()
+    }
+
+    /// Shortest rotation turning the direction `from` into the direction `to`. Opposite
+    /// directions are rotated by a half turn about an arbitrary perpendicular axis
+    pub fn from_arc(from: Vec3<S>, to: Vec3<S>) -> Quat<S> {
+        let (from, to) = (from.normalize(), to.normalize());
+        let dot = from.dot(to);
+        if S::ONE + dot <= S::EPSILON * (S::ONE + S::ONE) {
+            Quat::from_sv(S::ZERO, from.any_orthonormal())
+        } else {
+            Quat::from_sv(S::ONE + dot, from.cross(to)).normalize()
+        }
+    }
+
     pub fn nlerp(self, mut other: Quat<S>, amount: S) -> Quat<S> {
         if self.dot(other) < S::ZERO {
             other = -other;
@@ -46,7 +194,8 @@ impl<S: Float> Quat<S> {
 
     pub fn slerp(self, mut other: Quat<S>, amount: S) -> Quat<S> {
         let mut dot = self.dot(other);
-        let dot_threshold = S::ONE; // TODO cgmath uses a different constant
+        // Fall back to nlerp for nearly equal rotations, where sin(theta) vanishes
+        let dot_threshold = S::ONE - S::EPSILON.sqrt();
 
         if dot < S::ZERO {
             other = -other;
@@ -272,8 +421,8 @@ impl<S: Float> From<Quat<S>> for Mat4<S> {
     }
 }
 
-// TODO: TryFrom?
-/// Convert rotation matrix to quaternion
+/// Convert rotation matrix to quaternion. The matrix is not checked, see
+/// `Quat::try_from_rotation`
 impl<S: Float> From<Mat3<S>> for Quat<S> {
     fn from(mat: Mat3<S>) -> Self {
         let (m00, m01, m02) = mat.x.into();
@@ -286,19 +435,19 @@ impl<S: Float> From<Mat3<S>> for Quat<S> {
                 let four_xsq = omm22 - dif10;
                 let inv4x = S::HALF / four_xsq.sqrt();
                 Self::new(
+                    (m12 - m21) * inv4x,
                     four_xsq * inv4x,
                     (m01 + m10) * inv4x,
                     (m02 + m20) * inv4x,
-                    (m12 - m21) * inv4x,
                 )
             } else {
                 let four_ysq = omm22 + dif10;
                 let inv4y = S::HALF / four_ysq.sqrt();
                 Self::new(
+                    (m20 - m02) * inv4y,
                     (m01 + m10) * inv4y,
                     four_ysq * inv4y,
                     (m12 + m21) * inv4y,
-                    (m20 - m02) * inv4y,
                 )
             }
         } else {
@@ -308,19 +457,19 @@ impl<S: Float> From<Mat3<S>> for Quat<S> {
                 let four_zsq = opm22 - sum10;
                 let inv4z = S::HALF / four_zsq.sqrt();
                 Self::new(
+                    (m01 - m10) * inv4z,
                     (m02 + m20) * inv4z,
                     (m12 + m21) * inv4z,
                     four_zsq * inv4z,
-                    (m01 - m10) * inv4z,
                 )
             } else {
                 let four_wsq = opm22 + sum10;
                 let inv4w = S::HALF / four_wsq.sqrt();
                 Self::new(
+                    four_wsq * inv4w,
                     (m12 - m21) * inv4w,
                     (m20 - m02) * inv4w,
                     (m01 - m10) * inv4w,
-                    four_wsq * inv4w,
                 )
             }
         }
@@ -352,3 +501,239 @@ impl<S: Float> From<Quat<S>> for (S, S, S, S) {
         (x, y, z, s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Deg, Error, Euler, InnerSpace, Mat3, Mat4, Matrix, Quat, SquareMatrix, Vec3};
+
+    #[test]
+    fn test_from_mat3() {
+        assert_approx_eq!(
+            Quat::from(Mat3::<f64>::IDENTITY),
+            Quat::new(1.0, 0.0, 0.0, 0.0)
+        );
+        for euler in [
+            Euler::new(Deg(10.0f64), Deg(-70.0), Deg(25.0)),
+            Euler::new(Deg(170.0), Deg(20.0), Deg(-150.0)),
+            Euler::new(Deg(-90.0), Deg(180.0), Deg(0.0)),
+        ] {
+            let q = Quat::from(euler);
+            let r = Quat::from(Mat3::from(q));
+            // q and -q represent the same rotation
+            assert_approx_eq!(r * r.s.signum(), q * q.s.signum(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_from_mat3_round_trip() {
+        // Half turns make x, y or z the largest component, small angles make w the largest,
+        // which covers every branch of the conversion
+        let axes = [
+            Vec3::new(0.9, 0.3, -0.2),
+            Vec3::new(-0.2, 1.0, 0.4),
+            Vec3::new(0.3, -0.1, -1.0),
+            Vec3::new(1.0, -2.0, 0.5),
+        ];
+        for axis in axes {
+            for angle in [Deg(25.0f64), Deg(110.0), Deg(175.0), Deg(-160.0)] {
+                let q = Quat::from_axis_angle(axis.normalize(), angle);
+                let m = Mat3::from(q);
+                let r = Quat::from(m);
+                assert_approx_eq!(r * r.s.signum(), q * q.s.signum(), 1e-12);
+                let v = Vec3::new(0.3, -1.2, 2.0);
+                assert_approx_eq!(r * v, m * v, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let axis = Vec3::new(1.0, -2.0, 0.5).normalize();
+        let q = Quat::from_axis_angle(axis, Deg(70.0));
+        let (a, angle) = q.to_axis_angle();
+        assert_approx_eq!(a, axis, 1e-12);
+        assert_approx_eq!(Deg::from(angle).0, 70.0, 1e-12);
+        assert_approx_eq!(Mat3::from(q), Mat3::from_rotation(axis, Deg(70.0)), 1e-12);
+
+        let (a, angle) = Quat::<f64>::new(1.0, 0.0, 0.0, 0.0).to_axis_angle();
+        assert_eq!((a, angle.0), (Vec3::X, 0.0));
+    }
+
+    #[test]
+    fn test_from_rotation() {
+        let angle = Deg(35.0);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_approx_eq!(
+            Mat3::from(Quat::from_rotation_x(angle)),
+            Mat3::from_rotation_x(angle),
+            1e-12
+        );
+        assert_approx_eq!(
+            Mat3::from(Quat::from_rotation_y(angle)),
+            Mat3::from_rotation_y(angle),
+            1e-12
+        );
+        assert_approx_eq!(
+            Mat3::from(Quat::from_rotation_z(angle)),
+            Mat3::from_rotation_z(angle),
+            1e-12
+        );
+        assert_approx_eq!(
+            Mat3::from_rotation_y(angle),
+            Mat3::from_rotation(Vec3::Y, angle),
+            1e-12
+        );
+        // A quarter turn about y takes z to x
+        assert_approx_eq!(Quat::from_rotation_y(Deg(90.0)) * Vec3::Z, Vec3::X, 1e-12);
+        assert_approx_eq!(
+            Mat4::from_rotation_y(Deg(90.0)).transform_vector3(Vec3::Z),
+            Vec3::X,
+            1e-12
+        );
+        assert_approx_eq!(
+            Quat::from_rotation_z(Deg(90.0)) * v,
+            Vec3::new(-2.0, 1.0, 3.0),
+            1e-12
+        );
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), Deg(100.0));
+        assert_approx_eq!(q.ln().s, 0.0, 1e-12);
+        assert_approx_eq!(q.ln().exp(), q, 1e-12);
+        let general = Quat::new(0.5, -1.0, 2.0, 0.25);
+        assert_approx_eq!(general.ln().exp(), general, 1e-12);
+        let identity = Quat::new(1.0, 0.0, 0.0, 0.0);
+        assert_approx_eq!(identity.ln(), Quat::new(0.0, 0.0, 0.0, 0.0));
+
+        // The half turn of -1 is not lost
+        let minus_one = Quat::new(-1.0, 0.0, 0.0, 0.0);
+        let pi = std::f64::consts::PI;
+        assert_approx_eq!(minus_one.ln(), Quat::new(0.0, pi, 0.0, 0.0), 1e-12);
+        assert_approx_eq!(minus_one.ln().exp(), minus_one, 1e-12);
+        let minus_two = Quat::new(-2.0, 0.0, 0.0, 0.0);
+        assert_approx_eq!(minus_two.ln().exp(), minus_two, 1e-12);
+        assert_approx_eq!(minus_one.powf(0.5), Quat::new(0.0, 1.0, 0.0, 0.0), 1e-12);
+        let zero = Quat::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(zero.ln(), Quat::new(f64::NEG_INFINITY, 0.0, 0.0, 0.0));
+        assert_eq!(zero.ln().exp(), zero);
+
+        let half = q.powf(0.5);
+        assert_approx_eq!(half * half, q, 1e-12);
+        assert_approx_eq!(q.powf(0.3), identity.slerp(q, 0.3), 1e-12);
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let a = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), Deg(30.0));
+        let b = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), Deg(100.0));
+        assert_approx_eq!(Deg::from(a.angle_between(b)).0, 70.0, 1e-9);
+        assert_approx_eq!(Deg::from(a.angle_between(-b)).0, 70.0, 1e-9);
+        assert_approx_eq!(a.angle_between(a).0, 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        let a = Quat::from_rotation_z(Deg(10.0));
+        let b = Quat::from_rotation_z(Deg(100.0));
+        assert_approx_eq!(
+            a.rotate_towards(b, Deg(30.0)),
+            Quat::from_rotation_z(Deg(40.0)),
+            1e-12
+        );
+        assert_eq!(a.rotate_towards(b, Deg(120.0)), b);
+        assert_eq!(a.rotate_towards(b, Deg(0.0)), a);
+        // Shortest path through the opposite hemisphere
+        let c = -Quat::from_rotation_z(Deg(-50.0));
+        let r = a.rotate_towards(c, Deg(20.0));
+        assert_approx_eq!(
+            Deg::from(r.angle_between(Quat::from_rotation_z(Deg(-10.0)))).0,
+            0.0,
+            1e-9
+        );
+    }
+
+    #[test]
+    fn test_squad() {
+        let keys = [
+            Quat::from_rotation_x(Deg(0.0)),
+            Quat::from_rotation_y(Deg(60.0)),
+            Quat::from(Euler::new(Deg(30.0), Deg(80.0), Deg(-20.0))),
+            Quat::from_rotation_z(Deg(-45.0)),
+        ];
+        let (q1, q2) = (keys[1], keys[2]);
+        let a = q1.squad_intermediate(keys[0], q2);
+        let b = q2.squad_intermediate(q1, keys[3]);
+        assert_approx_eq!(q1.squad(q2, a, b, 0.0), q1, 1e-12);
+        assert_approx_eq!(q1.squad(q2, a, b, 1.0), q2, 1e-12);
+        assert_approx_eq!(q1.squad(q2, a, b, 0.4).magnitude(), 1.0, 1e-12);
+
+        // Evenly spaced rotations about a single axis reduce to slerp
+        let keys = [0.0, 20.0, 40.0, 60.0].map(|d| Quat::from_rotation_y(Deg(d)));
+        let a = keys[1].squad_intermediate(keys[0], keys[2]);
+        let b = keys[2].squad_intermediate(keys[1], keys[3]);
+        assert_approx_eq!(a, keys[1], 1e-12);
+        let expected = keys[1].slerp(keys[2], 0.3);
+        assert_approx_eq!(keys[1].squad(keys[2], a, b, 0.3), expected, 1e-12);
+    }
+
+    #[test]
+    fn test_try_from_rotation() {
+        let q = Quat::from(Euler::new(Deg(10.0f64), Deg(-70.0), Deg(25.0)));
+        let r = Quat::try_from_rotation(Mat3::from(q), 1e-9).unwrap();
+        assert_approx_eq!(r * r.s.signum(), q * q.s.signum(), 1e-12);
+
+        let scaled = Mat3::from(q) * 1.1;
+        assert_eq!(
+            Quat::try_from_rotation(scaled, 1e-9),
+            Err(Error::NotOrthonormal)
+        );
+        assert!(Quat::try_from_rotation(scaled, 0.5).is_ok());
+        let reflection = Mat3::from_nonuniform_scale(1.0, -1.0, 1.0);
+        assert_eq!(
+            Quat::try_from_rotation(reflection, 1e-9),
+            Err(Error::NotOrthonormal)
+        );
+        let mut nan = Mat3::from(q);
+        nan.z.x = f64::NAN;
+        assert_eq!(Quat::try_from_rotation(nan, 1e-9), Err(Error::NonFinite));
+    }
+
+    #[test]
+    fn test_look_rotation() {
+        let forward = Vec3::new(1.0, 2.0, -3.0);
+        let q = Quat::look_rotation(forward, Vec3::Y);
+        assert_approx_eq!(q * -Vec3::Z, forward.normalize(), 1e-12);
+        assert_approx_eq!((q * Vec3::X).y, 0.0, 1e-12);
+        assert_approx_eq!(
+            Mat3::from(q),
+            Mat3::look_to(forward, Vec3::Y).transpose(),
+            1e-12
+        );
+
+        // Up parallel to forward falls back to another up vector
+        let q = Quat::look_rotation(Vec3::new(0.0, -2.0, 0.0), Vec3::Y);
+        assert_approx_eq!(q * -Vec3::Z, Vec3::new(0.0, -1.0, 0.0), 1e-12);
+        assert_approx_eq!(q.magnitude(), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_from_arc() {
+        let cases = [
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 4.0, 6.0)),
+            (Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, -3.0)),
+            (Vec3::new(1.0, 1.0, 0.0), Vec3::new(-1.0, -1.0, 0.0)),
+        ];
+        for (from, to) in cases {
+            let q = Quat::from_arc(from, to);
+            assert_approx_eq!(q.magnitude(), 1.0, 1e-12);
+            assert_approx_eq!(q * from.normalize(), to.normalize(), 1e-12);
+        }
+        // The rotation axis is perpendicular to both directions
+        let q = Quat::from_arc(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_approx_eq!(q.v.normalize(), Vec3::new(0.0, -1.0, 0.0), 1e-12);
+    }
+}
@@ -0,0 +1,599 @@
+use crate::{
+    Aabb3, Capsule, Circle, Cylinder, Float, InnerSpace, Matrix, Obb3, Plane, Rect, Segment2,
+    Sphere, Triangle3, Vec2, Vec3,
+};
+
+/// Intersection of a ray with a surface in 3D. The distance is the ray parameter of the hit
+/// point, measured in multiples of the ray direction. The normal is the unit surface normal at
+/// the hit point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit3<S> {
+    pub distance: S,
+    pub normal: Vec3<S>,
+}
+
+/// Intersection of a ray with a triangle, see `Ray3::intersect_triangle`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TriangleHit<S> {
+    pub distance: S,
+    pub normal: Vec3<S>,
+    /// Weights of the three vertices at the hit point
+    pub barycentric: Vec3<S>,
+}
+
+/// Intersection of a ray with a curve in 2D, see `RayHit3`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit2<S> {
+    pub distance: S,
+    pub normal: Vec2<S>,
+}
+
+/// Half-line starting at `origin` and extending in the direction `dir`, which does not need to
+/// be a unit vector.
+///
+/// The intersection queries return the first hit at a non-negative distance. A ray starting
+/// inside a solid hits its boundary on the way out, with the normal pointing outwards
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray3<S> {
+    pub origin: Vec3<S>,
+    pub dir: Vec3<S>,
+}
+
+/// Half-line in 2D, see `Ray3`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray2<S> {
+    pub origin: Vec2<S>,
+    pub dir: Vec2<S>,
+}
+
+/// Slab test of a ray against an axis-aligned box, given per axis as origin, direction, minimum
+/// and maximum. Returns the distance of the hit, the axis of the face that was hit and the sign
+/// of its outward normal
+fn slab<S: Float, const N: usize>(axes: [(S, S, S, S); N]) -> Option<(S, usize, S)> {
+    let mut near = (-S::INFINITY, 0, S::ZERO);
+    let mut far = (S::INFINITY, 0, S::ZERO);
+    for (axis, (origin, dir, min, max)) in axes.into_iter().enumerate() {
+        if dir == S::ZERO {
+            if origin < min || origin > max {
+                return None;
+            }
+            continue;
+        }
+        let inv = dir.recip();
+        let (t_min, t_max) = ((min - origin) * inv, (max - origin) * inv);
+        let (t_in, t_out, sign) = if dir > S::ZERO {
+            (t_min, t_max, -S::ONE)
+        } else {
+            (t_max, t_min, S::ONE)
+        };
+        if t_in > near.0 {
+            near = (t_in, axis, sign);
+        }
+        if t_out < far.0 {
+            far = (t_out, axis, -sign);
+        }
+    }
+    if near.0 > far.0 || far.0 < S::ZERO || !far.0.is_finite() {
+        None
+    } else if near.0 >= S::ZERO {
+        Some(near)
+    } else {
+        Some(far)
+    }
+}
+
+/// Hit with the smallest non-negative distance
+fn nearest<S: Float, H, I>(hits: I, distance: impl Fn(&H) -> S) -> Option<H>
+where
+    I: IntoIterator<Item = Option<H>>,
+{
+    hits.into_iter()
+        .flatten()
+        .filter(|hit| distance(hit) >= S::ZERO)
+        .min_by(|a, b| distance(a).partial_cmp(&distance(b)).unwrap())
+}
+
+/// Roots of `a t^2 + 2 b t + c`, in ascending order for positive `a`
+fn solve_quadratic<S: Float>(a: S, b: S, c: S) -> Option<(S, S)> {
+    let discriminant = b * b - a * c;
+    if discriminant < S::ZERO || a == S::ZERO {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    Some(((-b - root) / a, (-b + root) / a))
+}
+
+impl<S: Float> Ray3<S> {
+    #[inline]
+    pub const fn new(origin: Vec3<S>, dir: Vec3<S>) -> Ray3<S> {
+        Ray3 { origin, dir }
+    }
+
+    /// Point at the distance `t` along the ray
+    #[inline]
+    pub fn at(&self, t: S) -> Vec3<S> {
+        self.origin + self.dir * t
+    }
+
+    /// Intersect a plane. The returned normal faces the ray origin. Rays parallel to the plane
+    /// never hit it
+    pub fn intersect_plane(&self, plane: &Plane<S>) -> Option<RayHit3<S>> {
+        let denom = plane.normal.dot(self.dir);
+        if denom == S::ZERO {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < S::ZERO {
+            return None;
+        }
+        let normal = plane.normal;
+        let normal = if denom > S::ZERO { -normal } else { normal };
+        Some(RayHit3 {
+            distance: t,
+            normal,
+        })
+    }
+
+    pub fn intersect_sphere(&self, sphere: &Sphere<S>) -> Option<RayHit3<S>> {
+        let Sphere { center, radius } = *sphere;
+        let oc = self.origin - center;
+        let (t0, t1) = solve_quadratic(
+            self.dir.magnitude2(),
+            oc.dot(self.dir),
+            oc.magnitude2() - radius * radius,
+        )?;
+        let t = if t0 >= S::ZERO { t0 } else { t1 };
+        if t < S::ZERO {
+            return None;
+        }
+        let normal = (self.at(t) - center) / radius;
+        Some(RayHit3 {
+            distance: t,
+            normal,
+        })
+    }
+
+    /// Intersect an axis-aligned box using the slab test
+    pub fn intersect_aabb(&self, aabb: &Aabb3<S>) -> Option<RayHit3<S>> {
+        let (o, d) = (self.origin, self.dir);
+        let (min, max) = (aabb.min, aabb.max);
+        let (t, axis, sign) = slab([
+            (o.x, d.x, min.x, max.x),
+            (o.y, d.y, min.y, max.y),
+            (o.z, d.z, min.z, max.z),
+        ])?;
+        let normal = [Vec3::X, Vec3::Y, Vec3::Z][axis] * sign;
+        Some(RayHit3 {
+            distance: t,
+            normal,
+        })
+    }
+
+    /// Intersect an oriented box
+    pub fn intersect_obb(&self, obb: &Obb3<S>) -> Option<RayHit3<S>> {
+        let to_local = obb.rotation.transpose();
+        let local = Ray3::new(to_local * (self.origin - obb.center), to_local * self.dir);
+        let hit = local.intersect_aabb(&Aabb3::new(-obb.extents, obb.extents))?;
+        Some(RayHit3 {
+            distance: hit.distance,
+            normal: obb.rotation * hit.normal,
+        })
+    }
+
+    /// Intersect a triangle with the Möller-Trumbore algorithm. Both sides of the triangle are
+    /// hit, the normal is the one of the counterclockwise winding
+    pub fn intersect_triangle(&self, triangle: &Triangle3<S>) -> Option<TriangleHit<S>> {
+        let Triangle3 { a, b, c } = *triangle;
+        let (e1, e2) = (b - a, c - a);
+        let p = self.dir.cross(e2);
+        let det = e1.dot(p);
+        let scale = e1.magnitude() * e2.magnitude() * self.dir.magnitude();
+        if det.abs() <= S::EPSILON * scale {
+            return None;
+        }
+        let inv_det = det.recip();
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < S::ZERO || u > S::ONE {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.dir.dot(q) * inv_det;
+        if v < S::ZERO || u + v > S::ONE {
+            return None;
+        }
+        let t = e2.dot(q) * inv_det;
+        if t < S::ZERO {
+            return None;
+        }
+        Some(TriangleHit {
+            distance: t,
+            normal: e1.cross(e2).normalize(),
+            barycentric: Vec3::new(S::ONE - u - v, u, v),
+        })
+    }
+
+    /// Hits of the ray with the infinite cylinder around the line through `a` and `b`, with
+    /// the position of each hit along the axis, from 0 at `a` to 1 at `b`
+    fn cylinder_hits(&self, a: Vec3<S>, b: Vec3<S>, radius: S) -> [Option<(RayHit3<S>, S)>; 2] {
+        let axis = b - a;
+        let axis2 = axis.magnitude2();
+        let oa = self.origin - a;
+        // Components perpendicular to the axis
+        let d = self.dir - axis * (self.dir.dot(axis) / axis2);
+        let o = oa - axis * (oa.dot(axis) / axis2);
+        let Some((t0, t1)) =
+            solve_quadratic(d.magnitude2(), o.dot(d), o.magnitude2() - radius * radius)
+        else {
+            return [None, None];
+        };
+        [t0, t1].map(|t| {
+            let p = self.at(t) - a;
+            let y = p.dot(axis) / axis2;
+            let normal = (p - axis * y) / radius;
+            Some((
+                RayHit3 {
+                    distance: t,
+                    normal,
+                },
+                y,
+            ))
+        })
+    }
+
+    /// Intersect a capsule, hitting its cylindrical body or either end cap
+    pub fn intersect_capsule(&self, capsule: &Capsule<S>) -> Option<RayHit3<S>> {
+        let (a, b, radius) = (capsule.segment.start, capsule.segment.end, capsule.radius);
+        let axis = b - a;
+        let [c0, c1] = self.cylinder_hits(a, b, radius);
+        let body = |hit: Option<(RayHit3<S>, S)>| {
+            hit.filter(|&(_, y)| y > S::ZERO && y < S::ONE)
+                .map(|(hit, _)| hit)
+        };
+        // Sphere hits only count on the outer half of each end cap
+        let cap = |center: Vec3<S>, outward: Vec3<S>| {
+            let oc = self.origin - center;
+            let roots = solve_quadratic(
+                self.dir.magnitude2(),
+                oc.dot(self.dir),
+                oc.magnitude2() - radius * radius,
+            );
+            let hit = move |t: S| {
+                let normal = (self.at(t) - center) / radius;
+                (normal.dot(outward) >= S::ZERO).then_some(RayHit3 {
+                    distance: t,
+                    normal,
+                })
+            };
+            match roots {
+                Some((t0, t1)) => [hit(t0), hit(t1)],
+                None => [None, None],
+            }
+        };
+        let [s0, s1] = cap(a, -axis);
+        let [s2, s3] = cap(b, axis);
+        nearest([body(c0), body(c1), s0, s1, s2, s3], |hit| hit.distance)
+    }
+
+    /// Intersect a cylinder, hitting its body or either flat cap
+    pub fn intersect_cylinder(&self, cylinder: &Cylinder<S>) -> Option<RayHit3<S>> {
+        let (a, b, radius) = (
+            cylinder.segment.start,
+            cylinder.segment.end,
+            cylinder.radius,
+        );
+        let axis = b - a;
+        let [c0, c1] = self.cylinder_hits(a, b, radius);
+        let body = |hit: Option<(RayHit3<S>, S)>| {
+            hit.filter(|&(_, y)| y >= S::ZERO && y <= S::ONE)
+                .map(|(hit, _)| hit)
+        };
+        let cap = |center: Vec3<S>, outward: Vec3<S>| {
+            let normal = outward.normalize();
+            let hit = self.intersect_plane(&Plane::from_point_normal(center, normal))?;
+            let p = self.at(hit.distance);
+            ((p - center).magnitude2() <= radius * radius).then_some(RayHit3 {
+                distance: hit.distance,
+                normal,
+            })
+        };
+        nearest([body(c0), body(c1), cap(a, -axis), cap(b, axis)], |hit| {
+            hit.distance
+        })
+    }
+}
+
+impl<S: Float> Ray2<S> {
+    #[inline]
+    pub const fn new(origin: Vec2<S>, dir: Vec2<S>) -> Ray2<S> {
+        Ray2 { origin, dir }
+    }
+
+    /// Point at the distance `t` along the ray
+    #[inline]
+    pub fn at(&self, t: S) -> Vec2<S> {
+        self.origin + self.dir * t
+    }
+
+    pub fn intersect_circle(&self, circle: &Circle<S>) -> Option<RayHit2<S>> {
+        let Circle { center, radius } = *circle;
+        let oc = self.origin - center;
+        let (t0, t1) = solve_quadratic(
+            self.dir.magnitude2(),
+            oc.dot(self.dir),
+            oc.magnitude2() - radius * radius,
+        )?;
+        let t = if t0 >= S::ZERO { t0 } else { t1 };
+        if t < S::ZERO {
+            return None;
+        }
+        let normal = (self.at(t) - center) / radius;
+        Some(RayHit2 {
+            distance: t,
+            normal,
+        })
+    }
+
+    /// Intersect a segment. The returned normal faces the ray origin.
+    /// Rays parallel to the segment never hit it
+    pub fn intersect_segment(&self, segment: &Segment2<S>) -> Option<RayHit2<S>> {
+        let (a, edge) = (segment.start, segment.end - segment.start);
+        let denom = self.dir.perp_dot(edge);
+        if denom == S::ZERO {
+            return None;
+        }
+        let w = a - self.origin;
+        let t = w.perp_dot(edge) / denom;
+        let s = w.perp_dot(self.dir) / denom;
+        if t < S::ZERO || s < S::ZERO || s > S::ONE {
+            return None;
+        }
+        let normal = Vec2::new(-edge.y, edge.x).normalize();
+        let normal = if normal.dot(self.dir) > S::ZERO {
+            -normal
+        } else {
+            normal
+        };
+        Some(RayHit2 {
+            distance: t,
+            normal,
+        })
+    }
+
+    /// Intersect an axis-aligned rectangle using the slab test
+    pub fn intersect_rect(&self, rect: &Rect<S>) -> Option<RayHit2<S>> {
+        let (o, d) = (self.origin, self.dir);
+        let (min, max) = (rect.min, rect.max);
+        let (t, axis, sign) = slab([(o.x, d.x, min.x, max.x), (o.y, d.y, min.y, max.y)])?;
+        let normal = [Vec2::new(S::ONE, S::ZERO), Vec2::new(S::ZERO, S::ONE)][axis] * sign;
+        Some(RayHit2 {
+            distance: t,
+            normal,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Aabb2, Aabb3, Capsule, Circle, Cylinder, Deg, InnerSpace, Mat3, Obb3, Plane, Ray2, Ray3,
+        Segment2, Sphere, Triangle3, Vec2, Vec3,
+    };
+
+    fn ray(origin: (f64, f64, f64), dir: (f64, f64, f64)) -> Ray3<f64> {
+        Ray3::new(Vec3::from(origin), Vec3::from(dir))
+    }
+
+    #[test]
+    fn test_plane() {
+        let r = ray((0.0, 5.0, 0.0), (0.0, -2.0, 0.0));
+        let hit = r.intersect_plane(&Plane::new(Vec3::Y, -1.0)).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3::Y);
+        let hit = r.intersect_plane(&Plane::new(-Vec3::Y, 1.0)).unwrap();
+        assert_eq!(hit.normal, Vec3::Y);
+        assert_eq!(r.intersect_plane(&Plane::new(Vec3::Y, -6.0)), None);
+        assert_eq!(r.intersect_plane(&Plane::new(Vec3::X, 0.0)), None);
+    }
+
+    #[test]
+    fn test_sphere() {
+        let sphere = Sphere::new(Vec3::new(0.0, 0.0, -5.0), 1.0);
+        let hit = ray((0.0, 0.0, 0.0), (0.0, 0.0, -1.0))
+            .intersect_sphere(&sphere)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 4.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::Z, 1e-12);
+        // Starting inside hits the far side
+        let hit = ray((0.0, 0.0, -5.0), (2.0, 0.0, 0.0))
+            .intersect_sphere(&sphere)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 0.5, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::X, 1e-12);
+        assert_eq!(
+            ray((0.0, 2.0, 0.0), (0.0, 0.0, -1.0)).intersect_sphere(&sphere),
+            None
+        );
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)).intersect_sphere(&sphere),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aabb() {
+        let aabb = Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 2.0, 1.0));
+        let hit = ray((-5.0, 0.5, 0.0), (1.0, 0.0, 0.0))
+            .intersect_aabb(&aabb)
+            .unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, -Vec3::X);
+        let hit = ray((0.0, 0.0, 0.0), (0.0, 1.0, 0.0))
+            .intersect_aabb(&aabb)
+            .unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3::Y);
+        let hit = ray((2.0, 4.0, 0.0), (-1.0, -1.0, 0.0))
+            .intersect_aabb(&aabb)
+            .unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3::Y);
+        assert_eq!(
+            ray((-5.0, 3.0, 0.0), (1.0, 0.0, 0.0)).intersect_aabb(&aabb),
+            None
+        );
+        assert_eq!(
+            ray((-5.0, 0.0, 0.0), (-1.0, 0.0, 0.0)).intersect_aabb(&aabb),
+            None
+        );
+        assert_eq!(
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)).intersect_aabb(&aabb),
+            None
+        );
+    }
+
+    #[test]
+    fn test_obb() {
+        let obb = Obb3::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Mat3::from_rotation_z(Deg(45.0)),
+        );
+        let hit = ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))
+            .intersect_obb(&obb)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 5.0 - 2.0f64.sqrt(), 1e-12);
+        assert_approx_eq!(hit.normal.x, -(0.5f64.sqrt()), 1e-12);
+        assert_approx_eq!(hit.normal.magnitude(), 1.0, 1e-12);
+        let miss = ray((-5.0, 1.5, 0.0), (1.0, 0.0, 0.0));
+        assert_eq!(miss.intersect_obb(&obb), None);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let (a, b, c) = (
+            Vec3::new(0.0, 0.0, -2.0),
+            Vec3::new(2.0, 0.0, -2.0),
+            Vec3::new(0.0, 2.0, -2.0),
+        );
+        let triangle = Triangle3::new(a, b, c);
+        let hit = ray((0.5, 0.5, 0.0), (0.0, 0.0, -1.0))
+            .intersect_triangle(&triangle)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 2.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::Z, 1e-12);
+        assert_approx_eq!(hit.barycentric, Vec3::new(0.5, 0.25, 0.25), 1e-12);
+        let p = a * hit.barycentric.x + b * hit.barycentric.y + c * hit.barycentric.z;
+        assert_approx_eq!(p, Vec3::new(0.5, 0.5, -2.0), 1e-12);
+        // Back faces are hit as well
+        let hit = ray((0.5, 0.5, -4.0), (0.0, 0.0, 1.0)).intersect_triangle(&triangle);
+        assert_approx_eq!(hit.unwrap().distance, 2.0, 1e-12);
+        assert_eq!(
+            ray((1.5, 1.5, 0.0), (0.0, 0.0, -1.0)).intersect_triangle(&triangle),
+            None
+        );
+        assert_eq!(
+            ray((0.5, 0.5, 0.0), (1.0, 0.0, 0.0)).intersect_triangle(&triangle),
+            None
+        );
+    }
+
+    #[test]
+    fn test_capsule() {
+        let capsule = Capsule::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0), 1.0);
+        let hit = ray((-5.0, 2.0, 0.0), (1.0, 0.0, 0.0))
+            .intersect_capsule(&capsule)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 4.0, 1e-12);
+        assert_approx_eq!(hit.normal, -Vec3::X, 1e-12);
+        // Along the axis into the end cap
+        let hit = ray((0.0, 10.0, 0.0), (0.0, -2.0, 0.0))
+            .intersect_capsule(&capsule)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 2.5, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::Y, 1e-12);
+        let hit = ray((-5.0, -0.5, 0.0), (1.0, 0.0, 0.0))
+            .intersect_capsule(&capsule)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 5.0 - 0.75f64.sqrt(), 1e-12);
+        // From inside
+        let hit = ray((0.0, 2.0, 0.0), (0.0, 0.0, 1.0))
+            .intersect_capsule(&capsule)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 1.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::Z, 1e-12);
+        assert_eq!(
+            ray((-5.0, 5.5, 0.0), (1.0, 0.0, 0.0)).intersect_capsule(&capsule),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cylinder() {
+        let cylinder = Cylinder::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0), 1.0);
+        let hit = ray((-5.0, 2.0, 0.0), (1.0, 0.0, 0.0))
+            .intersect_cylinder(&cylinder)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 4.0, 1e-12);
+        assert_approx_eq!(hit.normal, -Vec3::X, 1e-12);
+        let hit = ray((0.5, 10.0, 0.0), (0.0, -1.0, 0.0))
+            .intersect_cylinder(&cylinder)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 6.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec3::Y, 1e-12);
+        let hit = ray((0.0, 2.0, 0.0), (0.0, -1.0, 0.0))
+            .intersect_cylinder(&cylinder)
+            .unwrap();
+        assert_approx_eq!(hit.distance, 2.0, 1e-12);
+        assert_approx_eq!(hit.normal, -Vec3::Y, 1e-12);
+        assert_eq!(
+            ray((-5.0, -0.5, 0.0), (1.0, 0.0, 0.0)).intersect_cylinder(&cylinder),
+            None
+        );
+        assert_eq!(
+            ray((1.5, 10.0, 0.0), (0.0, -1.0, 0.0)).intersect_cylinder(&cylinder),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ray2() {
+        let r = Ray2::new(Vec2::new(-5.0, 0.0), Vec2::new(1.0, 0.0));
+        let hit = r
+            .intersect_circle(&Circle::new(Vec2::new(0.0, 0.0), 2.0))
+            .unwrap();
+        assert_approx_eq!(hit.distance, 3.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec2::new(-1.0, 0.0), 1e-12);
+        assert_eq!(
+            r.intersect_circle(&Circle::new(Vec2::new(0.0, 3.0), 2.0)),
+            None
+        );
+
+        let hit = r
+            .intersect_segment(&Segment2::new(Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0)))
+            .unwrap();
+        assert_approx_eq!(hit.distance, 6.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec2::new(-1.0, 0.0), 1e-12);
+        assert!(
+            r.intersect_segment(&Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, -1.0)))
+                .is_some()
+        );
+        assert_eq!(
+            r.intersect_segment(&Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 2.0))),
+            None
+        );
+        assert_eq!(
+            r.intersect_segment(&Segment2::new(Vec2::new(-6.0, 1.0), Vec2::new(-6.0, -1.0))),
+            None
+        );
+
+        let rect = Aabb2::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0));
+        let hit = r.intersect_rect(&rect).unwrap();
+        assert_approx_eq!(hit.distance, 4.0, 1e-12);
+        assert_approx_eq!(hit.normal, Vec2::new(-1.0, 0.0), 1e-12);
+        assert_eq!(
+            Ray2::new(Vec2::new(-5.0, 2.0), Vec2::new(1.0, 0.0)).intersect_rect(&rect),
+            None
+        );
+    }
+}
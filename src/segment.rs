@@ -0,0 +1,283 @@
+use crate::{Aabb2, Float, InnerSpace, Vec2, Vec3};
+
+macro_rules! impl_segment {
+    ($Segment:ident, $VecN:ident) => {
+        /// Line segment between two points
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub struct $Segment<S> {
+            pub start: $VecN<S>,
+            pub end: $VecN<S>,
+        }
+
+        impl<S: Float> $Segment<S> {
+            #[inline]
+            pub const fn new(start: $VecN<S>, end: $VecN<S>) -> $Segment<S> {
+                $Segment { start, end }
+            }
+
+            #[inline]
+            pub fn length(&self) -> S {
+                (self.end - self.start).magnitude()
+            }
+
+            /// Point at the parameter `t`, from 0 at the start to 1 at the end
+            #[inline]
+            pub fn at(&self, t: S) -> $VecN<S> {
+                self.start + (self.end - self.start) * t
+            }
+
+            /// Parameter of the point of the segment closest to `point`
+            pub fn closest_parameter(&self, point: $VecN<S>) -> S {
+                let dir = self.end - self.start;
+                let length2 = dir.magnitude2();
+                if length2 == S::ZERO {
+                    return S::ZERO;
+                }
+                ((point - self.start).dot(dir) / length2)
+                    .max(S::ZERO)
+                    .min(S::ONE)
+            }
+
+            /// Point of the segment closest to `point`
+            #[inline]
+            pub fn closest_point(&self, point: $VecN<S>) -> $VecN<S> {
+                self.at(self.closest_parameter(point))
+            }
+
+            /// Squared distance from the segment to `point`
+            #[inline]
+            pub fn squared_distance(&self, point: $VecN<S>) -> S {
+                (point - self.closest_point(point)).magnitude2()
+            }
+        }
+    };
+}
+
+impl_segment!(Segment2, Vec2);
+impl_segment!(Segment3, Vec3);
+
+/// Result of intersecting two segments in 2D
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SegmentIntersection<S> {
+    /// The segments cross or touch in a single point
+    Point(Vec2<S>),
+    /// The segments are collinear and share a stretch
+    Overlap(Segment2<S>),
+}
+
+impl<S: Float> Segment3<S> {
+    /// Parameters of the closest points of two segments, on `self` and on `other`. When the
+    /// segments are parallel one of the many pairs of closest points is picked
+    pub fn closest_parameters(&self, other: &Segment3<S>) -> (S, S) {
+        let clamp = |x: S| x.max(S::ZERO).min(S::ONE);
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let (a, e, f) = (d1.magnitude2(), d2.magnitude2(), d2.dot(r));
+        if a == S::ZERO && e == S::ZERO {
+            return (S::ZERO, S::ZERO);
+        }
+        if a == S::ZERO {
+            return (S::ZERO, clamp(f / e));
+        }
+        let c = d1.dot(r);
+        if e == S::ZERO {
+            return (clamp(-c / a), S::ZERO);
+        }
+        let b = d1.dot(d2);
+        let denom = a * e - b * b;
+        let s = if denom > S::ZERO {
+            clamp((b * f - c * e) / denom)
+        } else {
+            S::ZERO
+        };
+        let t = (b * s + f) / e;
+        if t < S::ZERO {
+            (clamp(-c / a), S::ZERO)
+        } else if t > S::ONE {
+            (clamp((b - c) / a), S::ONE)
+        } else {
+            (s, t)
+        }
+    }
+
+    /// Closest points of two segments, on `self` and on `other`
+    #[inline]
+    pub fn closest_points(&self, other: &Segment3<S>) -> (Vec3<S>, Vec3<S>) {
+        let (s, t) = self.closest_parameters(other);
+        (self.at(s), other.at(t))
+    }
+}
+
+impl<S: Float> Segment2<S> {
+    /// Where the segments meet, if they do. Collinear segments meet along the stretch they
+    /// share, which is a single point if they only touch at their ends
+    pub fn intersect(&self, other: &Segment2<S>) -> Option<SegmentIntersection<S>> {
+        let r = self.end - self.start;
+        let s = other.end - other.start;
+        let qp = other.start - self.start;
+        let denom = r.perp_dot(s);
+        if denom != S::ZERO {
+            let t = qp.perp_dot(s) / denom;
+            let u = qp.perp_dot(r) / denom;
+            let inside = |x: S| x >= S::ZERO && x <= S::ONE;
+            return (inside(t) && inside(u)).then(|| SegmentIntersection::Point(self.at(t)));
+        }
+        if qp.perp_dot(r) != S::ZERO || qp.perp_dot(s) != S::ZERO {
+            return None;
+        }
+        // Collinear, or one of the segments is a single point
+        let r2 = r.magnitude2();
+        if r2 == S::ZERO {
+            let on_other = other.squared_distance(self.start) == S::ZERO;
+            return on_other.then_some(SegmentIntersection::Point(self.start));
+        }
+        let t0 = qp.dot(r) / r2;
+        let t1 = t0 + s.dot(r) / r2;
+        let lo = t0.min(t1).max(S::ZERO);
+        let hi = t0.max(t1).min(S::ONE);
+        if lo > hi {
+            None
+        } else if lo == hi {
+            Some(SegmentIntersection::Point(self.at(lo)))
+        } else {
+            Some(SegmentIntersection::Overlap(Segment2::new(
+                self.at(lo),
+                self.at(hi),
+            )))
+        }
+    }
+
+    /// Part of the segment inside a rectangle, found with the Liang-Barsky algorithm, or
+    /// `None` if the segment misses it
+    pub fn clip_to_rect(&self, rect: &Aabb2<S>) -> Option<Segment2<S>> {
+        let d = self.end - self.start;
+        let (mut lo, mut hi) = (S::ZERO, S::ONE);
+        let edges = [
+            (-d.x, self.start.x - rect.min.x),
+            (d.x, rect.max.x - self.start.x),
+            (-d.y, self.start.y - rect.min.y),
+            (d.y, rect.max.y - self.start.y),
+        ];
+        for (p, q) in edges {
+            if p == S::ZERO {
+                if q < S::ZERO {
+                    return None;
+                }
+            } else if p < S::ZERO {
+                lo = lo.max(q / p);
+            } else {
+                hi = hi.min(q / p);
+            }
+        }
+        (lo <= hi).then(|| Segment2::new(self.at(lo), self.at(hi)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb2, InnerSpace, Segment2, Segment3, SegmentIntersection, Vec2, Vec3};
+
+    #[test]
+    fn test_closest_point() {
+        let s = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0));
+        assert_eq!(s.length(), 4.0);
+        assert_eq!(
+            s.closest_point(Vec3::new(1.0, 2.0, 0.0)),
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(s.closest_point(Vec3::new(-1.0, 2.0, 0.0)), s.start);
+        assert_eq!(s.closest_point(Vec3::new(6.0, 0.0, 1.0)), s.end);
+        assert_eq!(s.squared_distance(Vec3::new(6.0, 0.0, 1.0)), 5.0);
+        let point = Segment3::new(Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(point.closest_point(Vec3::new(0.0, 0.0, 0.0)), point.start);
+    }
+
+    #[test]
+    fn test_closest_points() {
+        let s = Segment3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0));
+        let crossing = Segment3::new(Vec3::new(1.0, -1.0, 2.0), Vec3::new(1.0, 1.0, 2.0));
+        assert_eq!(
+            s.closest_points(&crossing),
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 2.0))
+        );
+        let beyond = Segment3::new(Vec3::new(6.0, 1.0, 0.0), Vec3::new(6.0, 3.0, 0.0));
+        assert_eq!(
+            s.closest_points(&beyond),
+            (Vec3::new(4.0, 0.0, 0.0), Vec3::new(6.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            beyond.closest_points(&s),
+            (Vec3::new(6.0, 1.0, 0.0), Vec3::new(4.0, 0.0, 0.0))
+        );
+        let parallel = Segment3::new(Vec3::new(3.0, 1.0, 0.0), Vec3::new(7.0, 1.0, 0.0));
+        let (p, q) = s.closest_points(&parallel);
+        assert_eq!((p - q).magnitude2(), 1.0);
+        let point = Segment3::new(Vec3::new(2.0, 5.0, 0.0), Vec3::new(2.0, 5.0, 0.0));
+        assert_eq!(
+            s.closest_points(&point),
+            (Vec3::new(2.0, 0.0, 0.0), point.start)
+        );
+    }
+
+    #[test]
+    fn test_intersect_2d() {
+        let s = Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 4.0));
+        let crossing = Segment2::new(Vec2::new(0.0, 4.0), Vec2::new(4.0, 0.0));
+        assert_eq!(
+            s.intersect(&crossing),
+            Some(SegmentIntersection::Point(Vec2::new(2.0, 2.0)))
+        );
+        let short = Segment2::new(Vec2::new(0.0, 4.0), Vec2::new(1.0, 3.0));
+        assert_eq!(s.intersect(&short), None);
+        let touching = Segment2::new(Vec2::new(4.0, 4.0), Vec2::new(5.0, 0.0));
+        assert_eq!(
+            s.intersect(&touching),
+            Some(SegmentIntersection::Point(Vec2::new(4.0, 4.0)))
+        );
+        let parallel = Segment2::new(Vec2::new(1.0, 0.0), Vec2::new(5.0, 4.0));
+        assert_eq!(s.intersect(&parallel), None);
+        let overlapping = Segment2::new(Vec2::new(6.0, 6.0), Vec2::new(3.0, 3.0));
+        assert_eq!(
+            s.intersect(&overlapping),
+            Some(SegmentIntersection::Overlap(Segment2::new(
+                Vec2::new(3.0, 3.0),
+                Vec2::new(4.0, 4.0)
+            )))
+        );
+        let end_to_end = Segment2::new(Vec2::new(4.0, 4.0), Vec2::new(6.0, 6.0));
+        assert_eq!(
+            s.intersect(&end_to_end),
+            Some(SegmentIntersection::Point(Vec2::new(4.0, 4.0)))
+        );
+        let disjoint = Segment2::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0));
+        assert_eq!(s.intersect(&disjoint), None);
+        let point = Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0));
+        assert_eq!(
+            point.intersect(&s),
+            Some(SegmentIntersection::Point(Vec2::new(1.0, 1.0)))
+        );
+        assert_eq!(s.closest_point(Vec2::new(0.0, 2.0)), Vec2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_clip_to_rect() {
+        let rect = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 2.0));
+        let s = Segment2::new(Vec2::new(-2.0, 1.0), Vec2::new(6.0, 1.0));
+        assert_eq!(
+            s.clip_to_rect(&rect),
+            Some(Segment2::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0)))
+        );
+        let diagonal = Segment2::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0));
+        assert_eq!(
+            diagonal.clip_to_rect(&rect),
+            Some(Segment2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)))
+        );
+        let inside = Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 1.5));
+        assert_eq!(inside.clip_to_rect(&rect), Some(inside));
+        let outside = Segment2::new(Vec2::new(-1.0, 3.0), Vec2::new(5.0, 3.0));
+        assert_eq!(outside.clip_to_rect(&rect), None);
+        let missing = Segment2::new(Vec2::new(3.5, 3.0), Vec2::new(5.0, 1.5));
+        assert_eq!(missing.clip_to_rect(&rect), None);
+    }
+}
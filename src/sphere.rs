@@ -0,0 +1,287 @@
+use crate::{Aabb3, Float, InnerSpace, Mat4, Triangle3, Vec3};
+
+/// Ball given by its center and radius
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sphere<S> {
+    pub center: Vec3<S>,
+    pub radius: S,
+}
+
+impl<S: Float> Sphere<S> {
+    #[inline]
+    pub const fn new(center: Vec3<S>, radius: S) -> Sphere<S> {
+        Sphere { center, radius }
+    }
+
+    /// Bounding sphere of a point set with Ritter's method, which is fast but usually a few
+    /// percent larger than the minimal sphere. Returns `None` if there are no points
+    pub fn from_points<I>(points: I) -> Option<Sphere<S>>
+    where
+        I: IntoIterator<Item = Vec3<S>>,
+    {
+        // Ritter's method makes three passes over the points
+        let points: Vec<Vec3<S>> = points.into_iter().collect();
+        let farthest = |from: Vec3<S>| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).magnitude2() > (best - from).magnitude2() {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+        let x = farthest(*points.first()?);
+        let y = farthest(x);
+        let initial = Sphere::new((x + y) * S::HALF, (y - x).magnitude() * S::HALF);
+        Some(points.iter().fold(initial, |sphere, &p| sphere.extend(p)))
+    }
+
+    /// Minimal bounding sphere of a point set, computed with Welzl's algorithm in its move to
+    /// front variant. Returns `None` if there are no points
+    pub fn from_points_exact<I>(points: I) -> Option<Sphere<S>>
+    where
+        I: IntoIterator<Item = Vec3<S>>,
+    {
+        let mut points: Vec<Vec3<S>> = points.into_iter().collect();
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len();
+        Some(welzl(&mut points, n, &[]))
+    }
+
+    /// Sphere whose boundary passes through all support points, with the smallest radius
+    fn from_support(support: &[Vec3<S>]) -> Sphere<S> {
+        let two = S::ONE + S::ONE;
+        match *support {
+            [] => Sphere::new(Vec3::new(S::ZERO, S::ZERO, S::ZERO), -S::ONE),
+            [p] => Sphere::new(p, S::ZERO),
+            [p, q] => Sphere::new((p + q) * S::HALF, (q - p).magnitude() * S::HALF),
+            [p, q, r] => match Triangle3::new(p, q, r).circumcenter() {
+                Some(center) => Sphere::new(center, (p - center).magnitude()),
+                None => {
+                    // Collinear, the outer two points span the sphere
+                    let pairs = [(p, q), (q, r), (r, p)];
+                    let (p, q) = pairs.into_iter().fold(pairs[0], |best, pair| {
+                        if (pair.1 - pair.0).magnitude2() > (best.1 - best.0).magnitude2() {
+                            pair
+                        } else {
+                            best
+                        }
+                    });
+                    Sphere::from_support(&[p, q])
+                }
+            },
+            [p, q, r, s, ..] => {
+                let (a, b, c) = (q - p, r - p, s - p);
+                let det = a.dot(b.cross(c)) * two;
+                let scale = a.magnitude() * b.magnitude() * c.magnitude();
+                if det.abs() <= S::EPSILON * scale {
+                    // Coplanar, fall back to the circle through three of the points
+                    return Sphere::from_support(&[p, q, r]).extend(s);
+                }
+                let offset = (b.cross(c) * a.magnitude2()
+                    + c.cross(a) * b.magnitude2()
+                    + a.cross(b) * c.magnitude2())
+                    / det;
+                Sphere::new(p + offset, offset.magnitude())
+            }
+        }
+    }
+
+    /// Whether `point` is inside, allowing for the rounding of the support point construction
+    fn contains_approx(&self, point: Vec3<S>) -> bool {
+        let tolerance = S::EPSILON.sqrt() * (self.radius + S::ONE);
+        self.radius >= S::ZERO && (point - self.center).magnitude() <= self.radius + tolerance
+    }
+
+    /// Smallest sphere containing `self` and `point`, when grown towards the point
+    pub fn extend(&self, point: Vec3<S>) -> Sphere<S> {
+        let d = point - self.center;
+        let distance = d.magnitude();
+        if distance <= self.radius {
+            return *self;
+        }
+        let radius = (self.radius + distance) * S::HALF;
+        Sphere::new(
+            self.center + d * ((radius - self.radius) / distance),
+            radius,
+        )
+    }
+
+    /// Smallest sphere containing both spheres
+    pub fn merge(&self, other: &Sphere<S>) -> Sphere<S> {
+        let d = other.center - self.center;
+        let distance = d.magnitude();
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (distance + self.radius + other.radius) * S::HALF;
+        Sphere::new(
+            self.center + d * ((radius - self.radius) / distance),
+            radius,
+        )
+    }
+
+    /// Whether the point is inside the sphere or on its boundary
+    #[inline]
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        (point - self.center).magnitude2() <= self.radius * self.radius
+    }
+
+    /// Whether `other` lies entirely inside the sphere
+    #[inline]
+    pub fn contains_sphere(&self, other: &Sphere<S>) -> bool {
+        (other.center - self.center).magnitude() + other.radius <= self.radius
+    }
+
+    /// Whether the spheres overlap or touch
+    #[inline]
+    pub fn overlaps(&self, other: &Sphere<S>) -> bool {
+        let radius = self.radius + other.radius;
+        (other.center - self.center).magnitude2() <= radius * radius
+    }
+
+    /// Smallest axis-aligned box containing the sphere
+    #[inline]
+    pub fn aabb(&self) -> Aabb3<S> {
+        let r = self.radius;
+        Aabb3::from_center_extents(self.center, Vec3::new(r, r, r))
+    }
+
+    /// Sphere containing the sphere transformed by an affine transformation. The radius is
+    /// scaled by a Gershgorin bound on the largest singular value of the linear part, which is
+    /// exact for rotations and axis scales but conservative rather than tight in general
+    pub fn transform(&self, m: &Mat4<S>) -> Sphere<S> {
+        let columns = [m.x.truncate(), m.y.truncate(), m.z.truncate()];
+        let scale = columns
+            .iter()
+            .map(|a| columns.iter().fold(S::ZERO, |sum, b| sum + a.dot(*b).abs()))
+            .fold(S::ZERO, S::max)
+            .sqrt();
+        Sphere::new(m.transform_point3(self.center), self.radius * scale)
+    }
+}
+
+/// Minimal sphere containing the first `n` points with all `support` points on its boundary.
+/// Points that end up on the boundary are moved to the front, which speeds up later passes
+fn welzl<S: Float>(points: &mut [Vec3<S>], n: usize, support: &[Vec3<S>]) -> Sphere<S> {
+    let mut sphere = Sphere::from_support(support);
+    if support.len() == 4 {
+        return sphere;
+    }
+    for i in 0..n {
+        let p = points[i];
+        if !sphere.contains_approx(p) {
+            let mut next = [p; 4];
+            next[..support.len()].copy_from_slice(support);
+            sphere = welzl(points, i, &next[..=support.len()]);
+            points[..=i].rotate_right(1);
+        }
+    }
+    sphere
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Deg, InnerSpace, Mat4, Sphere, Vec3};
+
+    fn points() -> Vec<Vec3<f64>> {
+        (0..50)
+            .map(|i| {
+                let f = i as f64;
+                Vec3::new(
+                    (f * 1.7).sin() * 3.0,
+                    (f * 0.9).cos() * 2.0,
+                    (f * 2.3).sin(),
+                ) + Vec3::new(1.0, -1.0, 0.5)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = points();
+        let exact = Sphere::from_points_exact(points.iter().copied()).unwrap();
+        let ritter = Sphere::from_points(points.iter().copied()).unwrap();
+        for &p in &points {
+            assert!((p - exact.center).magnitude() <= exact.radius + 1e-9);
+            assert!(ritter.contains(p));
+        }
+        assert!(exact.radius <= ritter.radius + 1e-9);
+        assert!(ritter.radius <= exact.radius * 1.1);
+        assert_eq!(Sphere::<f64>::from_points([]), None);
+        assert_eq!(Sphere::<f64>::from_points_exact([]), None);
+    }
+
+    #[test]
+    fn test_exact() {
+        // Tetrahedron corners lie on the minimal sphere
+        let corners = [
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(-1.0, 1.0, -1.0),
+            Vec3::new(-1.0, -1.0, 1.0),
+        ];
+        let sphere = Sphere::from_points_exact(corners).unwrap();
+        assert_approx_eq!(sphere.center, Vec3::new(0.0, 0.0, 0.0), 1e-12);
+        assert_approx_eq!(sphere.radius, 3.0f64.sqrt(), 1e-12);
+        // Interior and collinear points do not matter
+        let points = [
+            Vec3::new(-2.0, 0.0, 0.0),
+            Vec3::new(0.5, 0.5, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+        ];
+        let sphere = Sphere::from_points_exact(points.iter().copied()).unwrap();
+        assert_approx_eq!(sphere.center, Vec3::new(0.0, 0.0, 0.0), 1e-12);
+        assert_approx_eq!(sphere.radius, 2.0, 1e-12);
+        let single = Sphere::from_points_exact([Vec3::new(1.0, 2.0, 3.0)]).unwrap();
+        assert_eq!(single, Sphere::new(Vec3::new(1.0, 2.0, 3.0), 0.0));
+    }
+
+    #[test]
+    fn test_merge() {
+        let a = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(Vec3::new(4.0, 0.0, 0.0), 1.0);
+        let merged = a.merge(&b);
+        assert_eq!(merged, Sphere::new(Vec3::new(2.0, 0.0, 0.0), 3.0));
+        assert!(merged.contains_sphere(&a) && merged.contains_sphere(&b));
+        let inner = Sphere::new(Vec3::new(0.5, 0.0, 0.0), 0.25);
+        assert_eq!(a.merge(&inner), a);
+        assert_eq!(inner.merge(&a), a);
+        assert!(!a.overlaps(&b));
+        assert!(a.overlaps(&Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0)));
+        assert!(a.contains(Vec3::new(0.0, 1.0, 0.0)));
+        assert!(!a.contains(Vec3::new(0.0, 1.0, 0.1)));
+        assert_eq!(
+            a.extend(Vec3::new(-3.0, 0.0, 0.0)),
+            Sphere::new(Vec3::new(-1.0, 0.0, 0.0), 2.0)
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let sphere = Sphere::new(Vec3::new(1.0, 0.0, 0.0), 2.0);
+        let m = Mat4::from_translation(Vec3::new(0.0, 5.0, 0.0))
+            * Mat4::from_rotation_z(Deg(90.0))
+            * Mat4::from_nonuniform_scale(1.0, 3.0, 0.5);
+        let transformed = sphere.transform(&m);
+        assert_approx_eq!(transformed.center, Vec3::new(0.0, 6.0, 0.0), 1e-12);
+        assert_approx_eq!(transformed.radius, 6.0, 1e-12);
+        // Scaling after a rotation stretches the sphere along a diagonal of the columns
+        let m = Mat4::from_nonuniform_scale(2.0, 1.0, 1.0) * Mat4::from_rotation_z(Deg(45.0));
+        let transformed = sphere.transform(&m);
+        assert_approx_eq!(
+            transformed.center,
+            Vec3::new(2f64.sqrt(), 0.5f64.sqrt(), 0.0),
+            1e-12
+        );
+        assert!(transformed.radius >= 4.0);
+        assert_approx_eq!(transformed.radius, 4.0, 1e-12);
+    }
+}
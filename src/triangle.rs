@@ -0,0 +1,433 @@
+use crate::{Float, InnerSpace, Ray3, Segment3, Vec2, Vec3, VectorSpace};
+
+/// Triangle with the vertices `a`, `b` and `c`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle3<S> {
+    pub a: Vec3<S>,
+    pub b: Vec3<S>,
+    pub c: Vec3<S>,
+}
+
+/// Triangle in 2D, see `Triangle3`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle2<S> {
+    pub a: Vec2<S>,
+    pub b: Vec2<S>,
+    pub c: Vec2<S>,
+}
+
+/// Sum of three values weighted by barycentric coordinates
+#[inline]
+fn interpolate<S: Float, V: VectorSpace<Scalar = S>>(weights: Vec3<S>, values: [V; 3]) -> V {
+    values[0] * weights.x + values[1] * weights.y + values[2] * weights.z
+}
+
+impl<S: Float> Triangle3<S> {
+    #[inline]
+    pub const fn new(a: Vec3<S>, b: Vec3<S>, c: Vec3<S>) -> Triangle3<S> {
+        Triangle3 { a, b, c }
+    }
+
+    /// Unit normal of the side from which the vertices appear in counterclockwise order. Not
+    /// finite for degenerate triangles
+    #[inline]
+    pub fn normal(&self) -> Vec3<S> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    #[inline]
+    pub fn area(&self) -> S {
+        (self.b - self.a).cross(self.c - self.a).magnitude() * S::HALF
+    }
+
+    #[inline]
+    pub fn centroid(&self) -> Vec3<S> {
+        (self.a + self.b + self.c) / (S::ONE + S::ONE + S::ONE)
+    }
+
+    /// Center of the circle through all three vertices, or `None` if the triangle is degenerate
+    pub fn circumcenter(&self) -> Option<Vec3<S>> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let n = ab.cross(ac);
+        let denom = n.magnitude2() * (S::ONE + S::ONE);
+        if denom <= S::EPSILON * ab.magnitude2() * ac.magnitude2() {
+            return None;
+        }
+        Some(self.a + (ac.cross(n) * ab.magnitude2() + n.cross(ab) * ac.magnitude2()) / denom)
+    }
+
+    /// Center of the circle touching all three edges
+    pub fn incenter(&self) -> Vec3<S> {
+        let la = (self.c - self.b).magnitude();
+        let lb = (self.a - self.c).magnitude();
+        let lc = (self.b - self.a).magnitude();
+        (self.a * la + self.b * lb + self.c * lc) / (la + lb + lc)
+    }
+
+    /// Weights of the vertices `a`, `b` and `c` that give the projection of `point` onto the
+    /// plane of the triangle. The weights sum to one and are all non-negative for points
+    /// inside. Returns `None` for degenerate triangles
+    pub fn barycentric(&self, point: Vec3<S>) -> Option<Vec3<S>> {
+        let (v0, v1, v2) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (d00, d01, d11) = (v0.dot(v0), v0.dot(v1), v1.dot(v1));
+        let (d20, d21) = (v2.dot(v0), v2.dot(v1));
+        let denom = d00 * d11 - d01 * d01;
+        if denom == S::ZERO {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Some(Vec3::new(S::ONE - v - w, v, w))
+    }
+
+    /// Point with the given barycentric weights of `a`, `b` and `c`
+    #[inline]
+    pub fn point_at(&self, weights: Vec3<S>) -> Vec3<S> {
+        interpolate(weights, [self.a, self.b, self.c])
+    }
+
+    /// Blend per-vertex attributes, such as UVs, colors or normals, by barycentric weights
+    #[inline]
+    pub fn interpolate<V: VectorSpace<Scalar = S>>(weights: Vec3<S>, values: [V; 3]) -> V {
+        interpolate(weights, values)
+    }
+
+    /// Edges from `a` to `b`, `b` to `c` and `c` to `a`
+    #[inline]
+    pub fn edges(&self) -> [Segment3<S>; 3] {
+        [
+            Segment3::new(self.a, self.b),
+            Segment3::new(self.b, self.c),
+            Segment3::new(self.c, self.a),
+        ]
+    }
+
+    /// Whether the projection of `point` onto the plane of the triangle lies inside the
+    /// triangle or on its boundary. Degenerate triangles contain nothing
+    pub fn contains(&self, point: Vec3<S>) -> bool {
+        let normal = (self.b - self.a).cross(self.c - self.a);
+        if normal.magnitude2() == S::ZERO {
+            return false;
+        }
+        let (a, b, c) = (self.a - point, self.b - point, self.c - point);
+        b.cross(c).dot(normal) >= S::ZERO
+            && c.cross(a).dot(normal) >= S::ZERO
+            && a.cross(b).dot(normal) >= S::ZERO
+    }
+
+    /// Point of the triangle closest to `point`, found by checking which vertex, edge or face
+    /// region the point lies in
+    pub fn closest_point(&self, point: Vec3<S>) -> Vec3<S> {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let (ab, ac) = (b - a, c - a);
+
+        let ap = point - a;
+        let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+        if d1 <= S::ZERO && d2 <= S::ZERO {
+            return a;
+        }
+
+        let bp = point - b;
+        let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+        if d3 >= S::ZERO && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= S::ZERO && d1 >= S::ZERO && d3 <= S::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+        if d6 >= S::ZERO && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= S::ZERO && d2 >= S::ZERO && d6 <= S::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= S::ZERO && d4 - d3 >= S::ZERO && d5 - d6 >= S::ZERO {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denom = (va + vb + vc).recip();
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    /// Squared distance from the triangle to `point`
+    #[inline]
+    pub fn squared_distance(&self, point: Vec3<S>) -> S {
+        (point - self.closest_point(point)).magnitude2()
+    }
+
+    /// Point where an edge of `other` passes through `self`
+    fn edge_crossing(&self, other: &Triangle3<S>) -> Option<Vec3<S>> {
+        other.edges().into_iter().find_map(|edge| {
+            let ray = Ray3::new(edge.start, edge.end - edge.start);
+            let hit = ray.intersect_triangle(self)?;
+            (hit.distance <= S::ONE).then(|| ray.at(hit.distance))
+        })
+    }
+
+    /// Closest points of two triangles, on `self` and on `other`. Both points are the same if
+    /// the triangles intersect
+    pub fn closest_points(&self, other: &Triangle3<S>) -> (Vec3<S>, Vec3<S>) {
+        if let Some(p) = self
+            .edge_crossing(other)
+            .or_else(|| other.edge_crossing(self))
+        {
+            return (p, p);
+        }
+        let mut best = (self.a, other.a);
+        let mut best_distance = S::INFINITY;
+        let mut consider = |(p, q): (Vec3<S>, Vec3<S>)| {
+            let distance = (q - p).magnitude2();
+            if distance < best_distance {
+                best = (p, q);
+                best_distance = distance;
+            }
+        };
+        for edge in self.edges() {
+            for other_edge in other.edges() {
+                consider(edge.closest_points(&other_edge));
+            }
+        }
+        for v in [other.a, other.b, other.c] {
+            consider((self.closest_point(v), v));
+        }
+        for v in [self.a, self.b, self.c] {
+            consider((v, other.closest_point(v)));
+        }
+        best
+    }
+
+    /// Distance between two triangles, zero if they intersect
+    #[inline]
+    pub fn distance(&self, other: &Triangle3<S>) -> S {
+        let (p, q) = self.closest_points(other);
+        (q - p).magnitude()
+    }
+}
+
+impl<S: Float> Triangle2<S> {
+    #[inline]
+    pub const fn new(a: Vec2<S>, b: Vec2<S>, c: Vec2<S>) -> Triangle2<S> {
+        Triangle2 { a, b, c }
+    }
+
+    /// Area, positive if the vertices are in counterclockwise order
+    #[inline]
+    pub fn signed_area(&self) -> S {
+        (self.b - self.a).perp_dot(self.c - self.a) * S::HALF
+    }
+
+    #[inline]
+    pub fn area(&self) -> S {
+        self.signed_area().abs()
+    }
+
+    #[inline]
+    pub fn centroid(&self) -> Vec2<S> {
+        (self.a + self.b + self.c) / (S::ONE + S::ONE + S::ONE)
+    }
+
+    /// Center of the circle through all three vertices, or `None` if the triangle is degenerate
+    pub fn circumcenter(&self) -> Option<Vec2<S>> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let denom = ab.perp_dot(ac) * (S::ONE + S::ONE);
+        if denom == S::ZERO {
+            return None;
+        }
+        let (lb, lc) = (ab.magnitude2(), ac.magnitude2());
+        let offset = Vec2::new(ac.y * lb - ab.y * lc, ab.x * lc - ac.x * lb) / denom;
+        Some(self.a + offset)
+    }
+
+    /// Center of the circle touching all three edges
+    pub fn incenter(&self) -> Vec2<S> {
+        let la = (self.c - self.b).magnitude();
+        let lb = (self.a - self.c).magnitude();
+        let lc = (self.b - self.a).magnitude();
+        (self.a * la + self.b * lb + self.c * lc) / (la + lb + lc)
+    }
+
+    /// Weights of the vertices `a`, `b` and `c` that give `point`. The weights sum to one and
+    /// are all non-negative for points inside. Returns `None` for degenerate triangles
+    pub fn barycentric(&self, point: Vec2<S>) -> Option<Vec3<S>> {
+        let (v0, v1, v2) = (self.b - self.a, self.c - self.a, point - self.a);
+        let denom = v0.perp_dot(v1);
+        if denom == S::ZERO {
+            return None;
+        }
+        let v = v2.perp_dot(v1) / denom;
+        let w = v0.perp_dot(v2) / denom;
+        Some(Vec3::new(S::ONE - v - w, v, w))
+    }
+
+    /// Whether the point is inside the triangle or on its boundary, for either winding
+    pub fn contains(&self, point: Vec2<S>) -> bool {
+        let d1 = (self.b - self.a).perp_dot(point - self.a);
+        let d2 = (self.c - self.b).perp_dot(point - self.b);
+        let d3 = (self.a - self.c).perp_dot(point - self.c);
+        let has_negative = d1 < S::ZERO || d2 < S::ZERO || d3 < S::ZERO;
+        let has_positive = d1 > S::ZERO || d2 > S::ZERO || d3 > S::ZERO;
+        !(has_negative && has_positive)
+    }
+
+    /// Point with the given barycentric weights of `a`, `b` and `c`
+    #[inline]
+    pub fn point_at(&self, weights: Vec3<S>) -> Vec2<S> {
+        interpolate(weights, [self.a, self.b, self.c])
+    }
+
+    /// Blend per-vertex attributes by barycentric weights, see `Triangle3::interpolate`
+    #[inline]
+    pub fn interpolate<V: VectorSpace<Scalar = S>>(weights: Vec3<S>, values: [V; 3]) -> V {
+        interpolate(weights, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Triangle2, Triangle3, Vec2, Vec3, Vec4, Zero};
+
+    fn triangle() -> Triangle3<f64> {
+        Triangle3::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn test_contains() {
+        let t = triangle();
+        assert!(t.contains(Vec3::new(1.0, 1.0, 5.0)));
+        assert!(t.contains(Vec3::new(2.0, 2.0, 0.0)));
+        assert!(t.contains(t.c));
+        assert!(!t.contains(Vec3::new(2.5, 2.5, 0.0)));
+        assert!(!t.contains(Vec3::new(-0.5, 1.0, 0.0)));
+        let flipped = Triangle3::new(t.a, t.c, t.b);
+        assert!(flipped.contains(Vec3::new(1.0, 1.0, -1.0)));
+        let degenerate = Triangle3::new(t.a, t.b, t.b * 2.0);
+        assert!(!degenerate.contains(Vec3::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let t = triangle();
+        let cases = [
+            ((1.0, 1.0, 3.0), (1.0, 1.0, 0.0)),
+            ((-1.0, -1.0, 1.0), (0.0, 0.0, 0.0)),
+            ((6.0, -1.0, 0.0), (4.0, 0.0, 0.0)),
+            ((-1.0, 6.0, 0.0), (0.0, 4.0, 0.0)),
+            ((2.0, -3.0, 0.0), (2.0, 0.0, 0.0)),
+            ((-3.0, 2.0, 1.0), (0.0, 2.0, 0.0)),
+            ((3.0, 3.0, -1.0), (2.0, 2.0, 0.0)),
+        ];
+        for (point, expected) in cases {
+            assert_eq!(t.closest_point(Vec3::from(point)), Vec3::from(expected));
+        }
+        assert_eq!(t.squared_distance(Vec3::new(3.0, 3.0, -1.0)), 3.0);
+    }
+
+    #[test]
+    fn test_triangle_distance() {
+        let t = triangle();
+        // Above the face
+        let above = Triangle3::new(
+            Vec3::new(1.0, 1.0, 2.0),
+            Vec3::new(2.0, 1.0, 3.0),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_eq!(
+            t.closest_points(&above),
+            (Vec3::new(1.0, 1.0, 0.0), above.a)
+        );
+        assert_eq!(t.distance(&above), 2.0);
+        // Crossing edges
+        let edge = Triangle3::new(
+            Vec3::new(2.0, -1.0, 1.0),
+            Vec3::new(2.0, -1.0, -1.0),
+            Vec3::new(2.0, -5.0, 0.0),
+        );
+        assert_approx_eq!(t.distance(&edge), 1.0, 1e-12);
+        // Piercing
+        let piercing = Triangle3::new(
+            Vec3::new(1.0, 1.0, -1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(-5.0, -5.0, 0.0),
+        );
+        let (p, q) = t.closest_points(&piercing);
+        assert_eq!(p, q);
+        assert_eq!(t.distance(&piercing), 0.0);
+        assert_eq!(piercing.distance(&t), 0.0);
+    }
+
+    #[test]
+    fn test_measures() {
+        let t = Triangle3::new(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(1.0, 0.0, 4.0),
+        );
+        assert_eq!(t.normal(), Vec3::X);
+        assert_eq!(t.area(), 6.0);
+        assert_eq!(t.centroid(), Vec3::new(1.0, 1.0, 4.0 / 3.0));
+        assert_approx_eq!(t.circumcenter().unwrap(), Vec3::new(1.0, 1.5, 2.0), 1e-12);
+        assert_approx_eq!(t.incenter(), Vec3::new(1.0, 1.0, 1.0), 1e-12);
+        let degenerate = Triangle3::new(t.a, t.b, t.b * 2.0 - t.a);
+        assert_eq!(degenerate.circumcenter(), None);
+
+        let t = Triangle2::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 3.0),
+            Vec2::new(4.0, 0.0),
+        );
+        assert_eq!(t.signed_area(), -6.0);
+        assert_eq!(t.area(), 6.0);
+        assert_approx_eq!(t.circumcenter().unwrap(), Vec2::new(2.0, 1.5), 1e-12);
+        assert_approx_eq!(t.incenter(), Vec2::new(1.0, 1.0), 1e-12);
+        assert!(t.contains(Vec2::new(1.0, 1.0)));
+        assert!(t.contains(Vec2::new(2.0, 0.0)));
+        assert!(!t.contains(Vec2::new(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let t = triangle();
+        let point = Vec3::new(1.0, 2.0, 7.0);
+        let weights = t.barycentric(point).unwrap();
+        assert_approx_eq!(weights, Vec3::new(0.25, 0.25, 0.5), 1e-12);
+        assert_approx_eq!(t.point_at(weights), Vec3::new(1.0, 2.0, 0.0), 1e-12);
+        assert_eq!(Triangle3::new(t.a, t.a, t.b).barycentric(point), None);
+
+        let t2 = Triangle2::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+        );
+        let weights = t2.barycentric(Vec2::new(1.0, 2.0)).unwrap();
+        assert_approx_eq!(weights, Vec3::new(0.25, 0.25, 0.5), 1e-12);
+        assert_approx_eq!(t2.point_at(weights), Vec2::new(1.0, 2.0), 1e-12);
+
+        let uv = Triangle3::interpolate(
+            weights,
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(0.0, 1.0),
+            ],
+        );
+        assert_approx_eq!(uv, Vec2::new(0.25, 0.5), 1e-12);
+        let color = Triangle2::interpolate(
+            Vec3::new(1.0, 0.0, 0.0),
+            [Vec4::new(1.0, 0.5, 0.0, 1.0), Vec4::ZERO, Vec4::ZERO],
+        );
+        assert_eq!(color, Vec4::new(1.0, 0.5, 0.0, 1.0));
+    }
+}
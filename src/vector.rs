@@ -79,6 +79,10 @@ macro_rules! impl_vector {
             pub fn recip(&self) -> Self {
                 Self { $($field: self.$field.recip()),+ }
             }
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
         }
 
         impl<S: Num> Zero for $VecN<S> {
@@ -501,6 +505,22 @@ impl<S: Num> Vec3<S> {
     }
 }
 
+impl<S: Float> Vec3<S> {
+    /// Unit vector orthogonal to `self`, which must not be zero
+    pub fn any_orthonormal(self) -> Vec3<S> {
+        // Cross with the coordinate axis least aligned with the vector
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+        let axis = if x <= y && x <= z {
+            Vec3::X
+        } else if y <= z {
+            Vec3::Y
+        } else {
+            Vec3::Z
+        };
+        self.cross(axis).normalize()
+    }
+}
+
 impl<S: Num> Vec4<S> {
     #[inline]
     pub fn truncate(self) -> Vec3<S> {
@@ -659,7 +679,7 @@ mod tests {
     }
 
     mod vec3 {
-        use crate::{Vec3, Zero};
+        use crate::{InnerSpace, Vec3, Zero};
 
         #[test]
         fn test_zero() {
@@ -705,6 +725,20 @@ mod tests {
             assert_eq!(b.z, 2.0);
         }
 
+        #[test]
+        fn test_any_orthonormal() {
+            for v in [
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, -2.0, 0.0),
+                Vec3::new(0.3, 0.2, -5.0),
+                Vec3::new(1.0, 1.0, 1.0),
+            ] {
+                let n = v.any_orthonormal();
+                assert_approx_eq!(n.magnitude(), 1.0, 1e-12);
+                assert_approx_eq!(n.dot(v), 0.0, 1e-12);
+            }
+        }
+
         #[test]
         fn test_div() {
             let a = Vec3::new(4.0, 6.0, 2.0) / 2.0;